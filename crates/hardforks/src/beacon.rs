@@ -0,0 +1,220 @@
+//! Beacon chain slot and epoch timing of timestamp based hardforks.
+
+use crate::{
+    ForkCondition,
+    ethereum::{
+        HOLESKY_BEACON_GENESIS_TIMESTAMP, HOODI_BEACON_GENESIS_TIMESTAMP,
+        MAINNET_BEACON_GENESIS_TIMESTAMP, SEPOLIA_BEACON_GENESIS_TIMESTAMP,
+    },
+};
+use alloy_chains::{Chain, NamedChain};
+
+/// The slot timing of a beacon chain.
+///
+/// Timestamp based hardforks of beacon chain backed networks activate at the first slot of an
+/// epoch, so their [`ForkCondition::Timestamp`] can be converted to and from slots and epochs.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BeaconTiming {
+    /// The timestamp of the beacon chain genesis, i.e. of slot 0.
+    pub genesis_timestamp: u64,
+    /// The duration of a slot, in seconds.
+    pub seconds_per_slot: u64,
+    /// The number of slots in an epoch.
+    pub slots_per_epoch: u64,
+}
+
+impl BeaconTiming {
+    /// The slot duration of all Ethereum beacon chains.
+    pub const SECONDS_PER_SLOT: u64 = 12;
+
+    /// The epoch length of all Ethereum beacon chains.
+    pub const SLOTS_PER_EPOCH: u64 = 32;
+
+    /// Creates the timing of a beacon chain with the given genesis timestamp and the Ethereum
+    /// slot duration and epoch length.
+    pub const fn new(genesis_timestamp: u64) -> Self {
+        Self {
+            genesis_timestamp,
+            seconds_per_slot: Self::SECONDS_PER_SLOT,
+            slots_per_epoch: Self::SLOTS_PER_EPOCH,
+        }
+    }
+
+    /// Ethereum mainnet beacon chain timing.
+    pub const fn mainnet() -> Self {
+        Self::new(MAINNET_BEACON_GENESIS_TIMESTAMP)
+    }
+
+    /// Ethereum sepolia beacon chain timing.
+    pub const fn sepolia() -> Self {
+        Self::new(SEPOLIA_BEACON_GENESIS_TIMESTAMP)
+    }
+
+    /// Ethereum holesky beacon chain timing.
+    pub const fn holesky() -> Self {
+        Self::new(HOLESKY_BEACON_GENESIS_TIMESTAMP)
+    }
+
+    /// Ethereum Hoodi beacon chain timing.
+    pub const fn hoodi() -> Self {
+        Self::new(HOODI_BEACON_GENESIS_TIMESTAMP)
+    }
+
+    /// Retrieves the beacon chain timing of the given chain, if it is known.
+    pub fn from_chain(chain: Chain) -> Option<Self> {
+        match chain.named()? {
+            NamedChain::Mainnet => Some(Self::mainnet()),
+            NamedChain::Sepolia => Some(Self::sepolia()),
+            NamedChain::Holesky => Some(Self::holesky()),
+            NamedChain::Hoodi => Some(Self::hoodi()),
+            _ => None,
+        }
+    }
+
+    /// Returns the duration of an epoch, in seconds.
+    pub const fn seconds_per_epoch(&self) -> u64 {
+        self.seconds_per_slot.saturating_mul(self.slots_per_epoch)
+    }
+
+    /// Returns the slot at the given timestamp, or `None` if it precedes the genesis.
+    pub const fn slot_at(&self, timestamp: u64) -> Option<u64> {
+        match timestamp.checked_sub(self.genesis_timestamp) {
+            Some(elapsed) => Some(elapsed / self.seconds_per_slot),
+            None => None,
+        }
+    }
+
+    /// Returns the epoch at the given timestamp, or `None` if it precedes the genesis.
+    pub const fn epoch_at(&self, timestamp: u64) -> Option<u64> {
+        match self.slot_at(timestamp) {
+            Some(slot) => Some(slot / self.slots_per_epoch),
+            None => None,
+        }
+    }
+
+    /// Returns the start timestamp of the given slot, or `None` if it overflows.
+    pub const fn slot_timestamp(&self, slot: u64) -> Option<u64> {
+        match slot.checked_mul(self.seconds_per_slot) {
+            Some(elapsed) => self.genesis_timestamp.checked_add(elapsed),
+            None => None,
+        }
+    }
+
+    /// Returns the start timestamp of the given epoch, or `None` if it overflows.
+    pub const fn epoch_timestamp(&self, epoch: u64) -> Option<u64> {
+        match epoch.checked_mul(self.slots_per_epoch) {
+            Some(slot) => self.slot_timestamp(slot),
+            None => None,
+        }
+    }
+
+    /// Returns true if the timestamp is the start of an epoch.
+    pub const fn is_epoch_boundary(&self, timestamp: u64) -> bool {
+        match timestamp.checked_sub(self.genesis_timestamp) {
+            Some(elapsed) => elapsed % self.seconds_per_epoch() == 0,
+            None => false,
+        }
+    }
+}
+
+impl ForkCondition {
+    /// Returns a [`ForkCondition::Timestamp`] activating at the start of the given slot, or `None`
+    /// if its timestamp overflows.
+    pub const fn from_slot(timing: &BeaconTiming, slot: u64) -> Option<Self> {
+        match timing.slot_timestamp(slot) {
+            Some(timestamp) => Some(Self::Timestamp(timestamp)),
+            None => None,
+        }
+    }
+
+    /// Returns a [`ForkCondition::Timestamp`] activating at the start of the given epoch, or
+    /// `None` if its timestamp overflows.
+    pub const fn from_epoch(timing: &BeaconTiming, epoch: u64) -> Option<Self> {
+        match timing.epoch_timestamp(epoch) {
+            Some(timestamp) => Some(Self::Timestamp(timestamp)),
+            None => None,
+        }
+    }
+
+    /// Returns the activation slot of the fork condition, if it is timestamp based and does not
+    /// precede the beacon chain genesis.
+    pub const fn slot(&self, timing: &BeaconTiming) -> Option<u64> {
+        match self {
+            Self::Timestamp(timestamp) => timing.slot_at(*timestamp),
+            _ => None,
+        }
+    }
+
+    /// Returns the activation epoch of the fork condition, if it is timestamp based and does not
+    /// precede the beacon chain genesis.
+    pub const fn epoch(&self, timing: &BeaconTiming) -> Option<u64> {
+        match self {
+            Self::Timestamp(timestamp) => timing.epoch_at(*timestamp),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ConsensusHardfork, EthereumChainHardforks, EthereumHardforks,
+        ethereum::HOLESKY_OSAKA_TIMESTAMP,
+    };
+
+    #[test]
+    fn slot_epoch_conversion() {
+        let timing = BeaconTiming::holesky();
+        let osaka = ForkCondition::Timestamp(HOLESKY_OSAKA_TIMESTAMP);
+        assert_eq!(osaka.epoch(&timing), Some(165_120));
+        assert_eq!(osaka.slot(&timing), Some(165_120 * 32));
+        assert_eq!(ForkCondition::from_epoch(&timing, 165_120), Some(osaka));
+        assert_eq!(ForkCondition::from_slot(&timing, 165_120 * 32), Some(osaka));
+        assert_eq!(ForkCondition::from_epoch(&timing, u64::MAX), None);
+        assert_eq!(ForkCondition::from_slot(&timing, u64::MAX / 12), None);
+
+        assert_eq!(timing.slot_at(HOLESKY_OSAKA_TIMESTAMP + 11), Some(165_120 * 32));
+        assert_eq!(timing.slot_at(HOLESKY_OSAKA_TIMESTAMP + 12), Some(165_120 * 32 + 1));
+        assert!(!timing.is_epoch_boundary(HOLESKY_OSAKA_TIMESTAMP + 12));
+
+        assert_eq!(timing.epoch_at(HOLESKY_BEACON_GENESIS_TIMESTAMP - 1), None);
+        assert_eq!(ForkCondition::ZERO_TIMESTAMP.epoch(&timing), None);
+        assert_eq!(ForkCondition::Block(100).slot(&timing), None);
+        assert_eq!(BeaconTiming::from_chain(Chain::optimism_mainnet()), None);
+    }
+
+    #[test]
+    fn builtin_timestamps_are_epoch_boundaries() {
+        for (chain, forks) in [
+            (Chain::mainnet(), EthereumChainHardforks::mainnet()),
+            (Chain::sepolia(), EthereumChainHardforks::sepolia()),
+            (Chain::holesky(), EthereumChainHardforks::holesky()),
+            (Chain::hoodi(), EthereumChainHardforks::hoodi()),
+        ] {
+            let timing = BeaconTiming::from_chain(chain).unwrap();
+            for (fork, condition) in &forks {
+                // Forks activated before the beacon chain genesis are active at genesis.
+                let Some(timestamp) = condition.as_timestamp() else { continue };
+                if timestamp <= timing.genesis_timestamp {
+                    continue;
+                }
+                assert!(timing.is_epoch_boundary(timestamp), "{chain} {fork} at {timestamp}");
+            }
+
+            // The execution layer activations match the consensus layer epochs.
+            for fork in ConsensusHardfork::VARIANTS {
+                let (Some(execution), Some(activation)) =
+                    (fork.execution_fork(), fork.activation(chain))
+                else {
+                    continue;
+                };
+                if let Some(epoch) = forks.ethereum_fork_activation(execution).epoch(&timing) {
+                    assert_eq!(epoch, activation.epoch, "{chain} {fork}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! [EIP-7840] blob parameters of hardfork schedules.
+//!
+//! [EIP-7840]: https://eips.ethereum.org/EIPS/eip-7840
+
+use crate::EthereumHardfork;
+use alloc::vec::Vec;
+
+/// The blob parameters of a hardfork, as defined by [EIP-7840].
+///
+/// [EIP-7840]: https://eips.ethereum.org/EIPS/eip-7840
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BlobParams {
+    /// The target number of blobs per block.
+    pub target: u64,
+    /// The maximum number of blobs per block.
+    pub max: u64,
+    /// The blob base fee update fraction.
+    pub base_fee_update_fraction: u64,
+}
+
+impl BlobParams {
+    /// Blob parameters introduced by [`EthereumHardfork::Cancun`] ([EIP-4844]).
+    ///
+    /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
+    pub const CANCUN: Self = Self { target: 3, max: 6, base_fee_update_fraction: 3_338_477 };
+
+    /// Blob parameters introduced by [`EthereumHardfork::Prague`] ([EIP-7691]), unchanged by
+    /// [`EthereumHardfork::Osaka`].
+    ///
+    /// [EIP-7691]: https://eips.ethereum.org/EIPS/eip-7691
+    pub const PRAGUE: Self = Self { target: 6, max: 9, base_fee_update_fraction: 5_007_716 };
+
+    /// Blob parameters of [`EthereumHardfork::Bpo1`] on the public networks.
+    pub const BPO1: Self = Self { target: 10, max: 15, base_fee_update_fraction: 8_346_193 };
+
+    /// Blob parameters of [`EthereumHardfork::Bpo2`] on the public networks.
+    pub const BPO2: Self = Self { target: 14, max: 21, base_fee_update_fraction: 11_684_671 };
+}
+
+/// The [`BlobParams`] of the blob-capable hardforks of a chain.
+///
+/// With the `serde` feature, it is (de)serialized like the `blobSchedule` of a geth-style genesis
+/// `config`, i.e. as a map keyed by the lowercase hardfork name (`cancun`, `prague`, `bpo1`...).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlobSchedule {
+    params: Vec<(EthereumHardfork, BlobParams)>,
+}
+
+impl BlobSchedule {
+    /// Creates a new [`BlobSchedule`] with the given parameters per hardfork.
+    pub fn new(params: impl IntoIterator<Item = (EthereumHardfork, BlobParams)>) -> Self {
+        let mut params = params.into_iter().collect::<Vec<_>>();
+        params.sort_by_key(|(fork, _)| *fork);
+        params.dedup_by_key(|(fork, _)| *fork);
+        Self { params }
+    }
+
+    /// Creates a new [`BlobSchedule`] with Mainnet configuration.
+    pub fn mainnet() -> Self {
+        Self::new([
+            (EthereumHardfork::Cancun, BlobParams::CANCUN),
+            (EthereumHardfork::Prague, BlobParams::PRAGUE),
+        ])
+    }
+
+    /// Creates a new [`BlobSchedule`] with Sepolia configuration.
+    pub fn sepolia() -> Self {
+        Self::public_testnet()
+    }
+
+    /// Creates a new [`BlobSchedule`] with Holesky configuration.
+    pub fn holesky() -> Self {
+        Self::public_testnet()
+    }
+
+    /// Creates a new [`BlobSchedule`] with Hoodi configuration.
+    pub fn hoodi() -> Self {
+        Self::public_testnet()
+    }
+
+    /// Creates a new [`BlobSchedule`] with Devnet configuration.
+    pub fn devnet() -> Self {
+        Self::public_testnet()
+    }
+
+    /// The blob schedule shared by the public testnets.
+    fn public_testnet() -> Self {
+        Self::new([
+            (EthereumHardfork::Cancun, BlobParams::CANCUN),
+            (EthereumHardfork::Prague, BlobParams::PRAGUE),
+            (EthereumHardfork::Osaka, BlobParams::PRAGUE),
+            (EthereumHardfork::Bpo1, BlobParams::BPO1),
+            (EthereumHardfork::Bpo2, BlobParams::BPO2),
+        ])
+    }
+
+    /// Returns the blob parameters of `fork`, if it has an entry.
+    pub fn get(&self, fork: EthereumHardfork) -> Option<BlobParams> {
+        let idx = self.params.binary_search_by(|(f, _)| f.cmp(&fork)).ok()?;
+        Some(self.params[idx].1)
+    }
+
+    /// Returns an iterator over the hardforks and their blob parameters, in canonical order.
+    pub fn iter(&self) -> core::slice::Iter<'_, (EthereumHardfork, BlobParams)> {
+        self.params.iter()
+    }
+
+    /// Returns `true` if the schedule has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Sets the blob parameters of `fork`, replacing any previous ones.
+    pub fn insert(&mut self, fork: EthereumHardfork, params: BlobParams) {
+        match self.params.binary_search_by(|(f, _)| f.cmp(&fork)) {
+            Ok(idx) => self.params[idx].1 = params,
+            Err(idx) => self.params.insert(idx, (fork, params)),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BlobSchedule {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.params.iter().map(|(fork, params)| (fork.name().to_lowercase(), params)),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BlobSchedule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let params =
+            alloc::collections::BTreeMap::<alloc::string::String, BlobParams>::deserialize(
+                deserializer,
+            )?;
+        params
+            .into_iter()
+            .map(|(key, params)| {
+                let fork = key.parse::<EthereumHardfork>().map_err(D::Error::custom)?;
+                if !fork.supports_blobs() {
+                    return Err(D::Error::custom(alloc::format!(
+                        "hardfork `{key}` doesn't support blobs"
+                    )));
+                }
+                Ok((fork, params))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+}
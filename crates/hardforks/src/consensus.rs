@@ -0,0 +1,195 @@
+//! Consensus layer hardforks and their activation on beacon chain networks.
+
+use crate::{EthereumHardfork, hardfork};
+use alloy_chains::{Chain, NamedChain};
+
+hardfork!(
+    /// The name of an Ethereum consensus layer (beacon chain) hardfork.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    ConsensusHardfork {
+        /// Phase 0: the launch of the beacon chain.
+        Phase0,
+        /// Altair: <https://github.com/ethereum/consensus-specs/tree/dev/specs/altair>.
+        Altair,
+        /// Bellatrix, paired with [`EthereumHardfork::Paris`]: <https://github.com/ethereum/consensus-specs/tree/dev/specs/bellatrix>.
+        Bellatrix,
+        /// Capella, paired with [`EthereumHardfork::Shanghai`]: <https://github.com/ethereum/consensus-specs/tree/dev/specs/capella>.
+        Capella,
+        /// Deneb, paired with [`EthereumHardfork::Cancun`]: <https://github.com/ethereum/consensus-specs/tree/dev/specs/deneb>.
+        Deneb,
+        /// Electra, paired with [`EthereumHardfork::Prague`]: <https://github.com/ethereum/consensus-specs/tree/dev/specs/electra>.
+        Electra,
+        /// Fulu, paired with [`EthereumHardfork::Osaka`]: <https://github.com/ethereum/consensus-specs/tree/dev/specs/fulu>.
+        Fulu,
+        /// Gloas, paired with [`EthereumHardfork::Amsterdam`]: <https://github.com/ethereum/consensus-specs/tree/dev/specs/gloas>.
+        Gloas,
+    }
+);
+
+/// The fork version and activation epoch of a [`ConsensusHardfork`] on a network.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConsensusForkActivation {
+    /// The fork version.
+    pub version: [u8; 4],
+    /// The epoch at which the fork activates.
+    pub epoch: u64,
+}
+
+impl ConsensusForkActivation {
+    /// Creates a new [`ConsensusForkActivation`].
+    pub const fn new(version: [u8; 4], epoch: u64) -> Self {
+        Self { version, epoch }
+    }
+}
+
+impl ConsensusHardfork {
+    /// Returns the execution layer hardfork activated together with this hardfork, if any.
+    ///
+    /// [`ConsensusHardfork::Phase0`] and [`ConsensusHardfork::Altair`] predate the merge and have
+    /// none.
+    pub const fn execution_fork(&self) -> Option<EthereumHardfork> {
+        match self {
+            Self::Phase0 | Self::Altair => None,
+            Self::Bellatrix => Some(EthereumHardfork::Paris),
+            Self::Capella => Some(EthereumHardfork::Shanghai),
+            Self::Deneb => Some(EthereumHardfork::Cancun),
+            Self::Electra => Some(EthereumHardfork::Prague),
+            Self::Fulu => Some(EthereumHardfork::Osaka),
+            Self::Gloas => Some(EthereumHardfork::Amsterdam),
+        }
+    }
+
+    /// Retrieves the fork version and activation epoch of the hardfork on the given chain.
+    ///
+    /// Returns `None` if the chain is not known, or the hardfork is not scheduled on it.
+    pub fn activation(&self, chain: Chain) -> Option<ConsensusForkActivation> {
+        let forks: &[(Self, ConsensusForkActivation)] = match chain.named()? {
+            NamedChain::Mainnet => &Self::mainnet(),
+            NamedChain::Sepolia => &Self::sepolia(),
+            NamedChain::Holesky => &Self::holesky(),
+            NamedChain::Hoodi => &Self::hoodi(),
+            _ => return None,
+        };
+        forks.iter().find(|(fork, _)| fork == self).map(|(_, activation)| *activation)
+    }
+
+    /// Ethereum mainnet list of consensus layer hardforks.
+    pub const fn mainnet() -> [(Self, ConsensusForkActivation); 6] {
+        [
+            (Self::Phase0, ConsensusForkActivation::new([0x00, 0x00, 0x00, 0x00], 0)),
+            (Self::Altair, ConsensusForkActivation::new([0x01, 0x00, 0x00, 0x00], 74_240)),
+            (Self::Bellatrix, ConsensusForkActivation::new([0x02, 0x00, 0x00, 0x00], 144_896)),
+            (Self::Capella, ConsensusForkActivation::new([0x03, 0x00, 0x00, 0x00], 194_048)),
+            (Self::Deneb, ConsensusForkActivation::new([0x04, 0x00, 0x00, 0x00], 269_568)),
+            (Self::Electra, ConsensusForkActivation::new([0x05, 0x00, 0x00, 0x00], 364_032)),
+        ]
+    }
+
+    /// Ethereum sepolia list of consensus layer hardforks.
+    pub const fn sepolia() -> [(Self, ConsensusForkActivation); 7] {
+        [
+            (Self::Phase0, ConsensusForkActivation::new([0x90, 0x00, 0x00, 0x69], 0)),
+            (Self::Altair, ConsensusForkActivation::new([0x90, 0x00, 0x00, 0x70], 50)),
+            (Self::Bellatrix, ConsensusForkActivation::new([0x90, 0x00, 0x00, 0x71], 100)),
+            (Self::Capella, ConsensusForkActivation::new([0x90, 0x00, 0x00, 0x72], 56_832)),
+            (Self::Deneb, ConsensusForkActivation::new([0x90, 0x00, 0x00, 0x73], 132_608)),
+            (Self::Electra, ConsensusForkActivation::new([0x90, 0x00, 0x00, 0x74], 222_464)),
+            (Self::Fulu, ConsensusForkActivation::new([0x90, 0x00, 0x00, 0x75], 272_640)),
+        ]
+    }
+
+    /// Ethereum holesky list of consensus layer hardforks.
+    pub const fn holesky() -> [(Self, ConsensusForkActivation); 7] {
+        [
+            (Self::Phase0, ConsensusForkActivation::new([0x01, 0x01, 0x70, 0x00], 0)),
+            (Self::Altair, ConsensusForkActivation::new([0x02, 0x01, 0x70, 0x00], 0)),
+            (Self::Bellatrix, ConsensusForkActivation::new([0x03, 0x01, 0x70, 0x00], 0)),
+            (Self::Capella, ConsensusForkActivation::new([0x04, 0x01, 0x70, 0x00], 256)),
+            (Self::Deneb, ConsensusForkActivation::new([0x05, 0x01, 0x70, 0x00], 29_696)),
+            (Self::Electra, ConsensusForkActivation::new([0x06, 0x01, 0x70, 0x00], 115_968)),
+            (Self::Fulu, ConsensusForkActivation::new([0x07, 0x01, 0x70, 0x00], 165_120)),
+        ]
+    }
+
+    /// Ethereum Hoodi list of consensus layer hardforks.
+    pub const fn hoodi() -> [(Self, ConsensusForkActivation); 7] {
+        [
+            (Self::Phase0, ConsensusForkActivation::new([0x10, 0x00, 0x09, 0x10], 0)),
+            (Self::Altair, ConsensusForkActivation::new([0x20, 0x00, 0x09, 0x10], 0)),
+            (Self::Bellatrix, ConsensusForkActivation::new([0x30, 0x00, 0x09, 0x10], 0)),
+            (Self::Capella, ConsensusForkActivation::new([0x40, 0x00, 0x09, 0x10], 0)),
+            (Self::Deneb, ConsensusForkActivation::new([0x50, 0x00, 0x09, 0x10], 0)),
+            (Self::Electra, ConsensusForkActivation::new([0x60, 0x00, 0x09, 0x10], 2_048)),
+            (Self::Fulu, ConsensusForkActivation::new([0x70, 0x00, 0x09, 0x10], 50_688)),
+        ]
+    }
+}
+
+impl EthereumHardfork {
+    /// Returns the consensus layer hardfork activated together with this hardfork, if any.
+    pub const fn consensus_fork(&self) -> Option<ConsensusHardfork> {
+        match self {
+            Self::Paris => Some(ConsensusHardfork::Bellatrix),
+            Self::Shanghai => Some(ConsensusHardfork::Capella),
+            Self::Cancun => Some(ConsensusHardfork::Deneb),
+            Self::Prague => Some(ConsensusHardfork::Electra),
+            Self::Osaka => Some(ConsensusHardfork::Fulu),
+            Self::Amsterdam => Some(ConsensusHardfork::Gloas),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use core::str::FromStr;
+
+    #[test]
+    fn execution_fork_mapping() {
+        for fork in ConsensusHardfork::VARIANTS {
+            if let Some(execution) = fork.execution_fork() {
+                assert_eq!(execution.consensus_fork(), Some(*fork));
+            }
+        }
+        for fork in EthereumHardfork::VARIANTS {
+            if let Some(consensus) = fork.consensus_fork() {
+                assert_eq!(consensus.execution_fork(), Some(*fork));
+            }
+        }
+        assert_eq!(ConsensusHardfork::Altair.execution_fork(), None);
+        assert_eq!(EthereumHardfork::Bpo1.consensus_fork(), None);
+        assert_eq!(ConsensusHardfork::from_str("deneb").unwrap(), ConsensusHardfork::Deneb);
+    }
+
+    #[test]
+    fn consensus_fork_activations() {
+        assert_eq!(
+            ConsensusHardfork::Electra.activation(Chain::mainnet()),
+            Some(ConsensusForkActivation::new([0x05, 0x00, 0x00, 0x00], 364_032))
+        );
+        assert_eq!(
+            ConsensusHardfork::Fulu.activation(Chain::hoodi()),
+            Some(ConsensusForkActivation::new([0x70, 0x00, 0x09, 0x10], 50_688))
+        );
+        assert_eq!(ConsensusHardfork::Fulu.activation(Chain::mainnet()), None);
+        assert_eq!(ConsensusHardfork::Deneb.activation(Chain::optimism_mainnet()), None);
+
+        // Forks are listed in order, and fork versions are unique across networks.
+        let mut versions = Vec::new();
+        for forks in [
+            ConsensusHardfork::mainnet().as_slice(),
+            ConsensusHardfork::sepolia().as_slice(),
+            ConsensusHardfork::holesky().as_slice(),
+            ConsensusHardfork::hoodi().as_slice(),
+        ] {
+            assert!(forks.is_sorted_by_key(|(fork, activation)| (*fork, activation.epoch)));
+            versions.extend(forks.iter().map(|(_, activation)| activation.version));
+        }
+        let count = versions.len();
+        versions.sort_unstable();
+        versions.dedup();
+        assert_eq!(versions.len(), count);
+    }
+}
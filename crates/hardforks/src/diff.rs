@@ -0,0 +1,137 @@
+//! Differences between two hardfork schedules.
+
+use crate::ForkCondition;
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::fmt;
+
+/// A difference in the activation of a single fork between two hardfork schedules.
+///
+/// Forks activated by [`ForkCondition::Never`] are treated as not scheduled.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "change", rename_all = "camelCase"))]
+pub enum ForkChange<H> {
+    /// The fork is only scheduled in the new schedule.
+    Added {
+        /// The fork.
+        fork: H,
+        /// The activation of the fork in the new schedule.
+        condition: ForkCondition,
+    },
+    /// The fork is only scheduled in the old schedule.
+    Removed {
+        /// The fork.
+        fork: H,
+        /// The activation of the fork in the old schedule.
+        condition: ForkCondition,
+    },
+    /// The fork is scheduled in both schedules, with different activations.
+    Moved {
+        /// The fork.
+        fork: H,
+        /// The activation of the fork in the old schedule.
+        old: ForkCondition,
+        /// The activation of the fork in the new schedule.
+        new: ForkCondition,
+    },
+}
+
+impl<H: Copy + Ord> ForkChange<H> {
+    /// Compares two schedules, returning the changes from `old` to `new` in canonical fork order.
+    pub fn diff(
+        old: impl IntoIterator<Item = (H, ForkCondition)>,
+        new: impl IntoIterator<Item = (H, ForkCondition)>,
+    ) -> Vec<Self> {
+        let scheduled = |forks: &mut dyn Iterator<Item = (H, ForkCondition)>| {
+            forks
+                .filter(|(_, condition)| *condition != ForkCondition::Never)
+                .collect::<BTreeMap<_, _>>()
+        };
+        let mut old = scheduled(&mut old.into_iter());
+        let new = scheduled(&mut new.into_iter());
+
+        let mut changes = Vec::new();
+        for (fork, condition) in new {
+            match old.remove(&fork) {
+                None => changes.push(Self::Added { fork, condition }),
+                Some(old) if old != condition => {
+                    changes.push(Self::Moved { fork, old, new: condition })
+                }
+                Some(_) => {}
+            }
+        }
+        changes.extend(old.into_iter().map(|(fork, condition)| Self::Removed { fork, condition }));
+        changes.sort_by_key(Self::fork);
+        changes
+    }
+}
+
+impl<H: Copy> ForkChange<H> {
+    /// Returns the changed fork.
+    pub const fn fork(&self) -> H {
+        match self {
+            Self::Added { fork, .. } | Self::Removed { fork, .. } | Self::Moved { fork, .. } => {
+                *fork
+            }
+        }
+    }
+}
+
+impl<H: fmt::Display> fmt::Display for ForkChange<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { fork, condition } => write!(f, "+ {fork}: {condition}"),
+            Self::Removed { fork, condition } => write!(f, "- {fork}: {condition}"),
+            Self::Moved { fork, old, new } => write!(f, "~ {fork}: {old} -> {new}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EthereumChainHardforks, EthereumHardfork};
+    use alloc::string::ToString;
+
+    #[test]
+    fn schedule_diff() {
+        let old = EthereumChainHardforks::sepolia();
+        let new = EthereumChainHardforks::new(
+            old.clone()
+                .into_iter()
+                .filter(|(fork, _)| *fork != EthereumHardfork::Bpo2)
+                .map(|(fork, condition)| match fork {
+                    EthereumHardfork::Osaka => (fork, ForkCondition::Timestamp(2_000_000_000)),
+                    EthereumHardfork::Bpo1 => (fork, ForkCondition::Never),
+                    _ => (fork, condition),
+                })
+                .chain([(EthereumHardfork::Amsterdam, ForkCondition::Timestamp(2_100_000_000))]),
+        );
+
+        let changes = old.diff(&new);
+        assert_eq!(
+            changes,
+            [
+                ForkChange::Moved {
+                    fork: EthereumHardfork::Osaka,
+                    old: old.get(EthereumHardfork::Osaka).unwrap(),
+                    new: ForkCondition::Timestamp(2_000_000_000),
+                },
+                ForkChange::Removed {
+                    fork: EthereumHardfork::Bpo1,
+                    condition: old.get(EthereumHardfork::Bpo1).unwrap(),
+                },
+                ForkChange::Removed {
+                    fork: EthereumHardfork::Bpo2,
+                    condition: old.get(EthereumHardfork::Bpo2).unwrap(),
+                },
+                ForkChange::Added {
+                    fork: EthereumHardfork::Amsterdam,
+                    condition: ForkCondition::Timestamp(2_100_000_000),
+                },
+            ]
+        );
+        assert_eq!(changes[3].to_string(), "+ Amsterdam: timestamp:2100000000");
+        assert!(new.diff(&new).is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+//! Engine API method versions of post-merge hardforks.
+//!
+//! See the [execution-apis] specification.
+//!
+//! [execution-apis]: https://github.com/ethereum/execution-apis/tree/main/src/engine
+
+use crate::EthereumHardfork;
+use core::fmt;
+
+/// An Engine API method whose version depends on the active hardfork.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EngineApiMethod {
+    /// `engine_newPayloadVX`.
+    NewPayload,
+    /// `engine_getPayloadVX`.
+    GetPayload,
+    /// `engine_forkchoiceUpdatedVX`.
+    ForkchoiceUpdated,
+}
+
+impl EngineApiMethod {
+    /// Returns the name of the method, without its version suffix.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::NewPayload => "engine_newPayload",
+            Self::GetPayload => "engine_getPayload",
+            Self::ForkchoiceUpdated => "engine_forkchoiceUpdated",
+        }
+    }
+}
+
+impl fmt::Display for EngineApiMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The Engine API method and structure versions to use for payloads of a hardfork.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct EngineApiVersions {
+    /// The `engine_newPayload` version.
+    pub new_payload: u8,
+    /// The `engine_getPayload` version.
+    pub get_payload: u8,
+    /// The `engine_forkchoiceUpdated` version.
+    pub forkchoice_updated: u8,
+    /// The `ExecutionPayload` version.
+    pub execution_payload: u8,
+    /// The `PayloadAttributes` version.
+    pub payload_attributes: u8,
+}
+
+impl EngineApiVersions {
+    /// Versions of [`EthereumHardfork::Paris`] payloads.
+    pub const PARIS: Self = Self::new(1, 1, 1, 1, 1);
+
+    /// Versions of [`EthereumHardfork::Shanghai`] payloads.
+    pub const SHANGHAI: Self = Self::new(2, 2, 2, 2, 2);
+
+    /// Versions of [`EthereumHardfork::Cancun`] payloads.
+    pub const CANCUN: Self = Self::new(3, 3, 3, 3, 3);
+
+    /// Versions of [`EthereumHardfork::Prague`] payloads.
+    pub const PRAGUE: Self = Self::new(4, 4, 3, 3, 3);
+
+    /// Versions of [`EthereumHardfork::Osaka`] payloads.
+    pub const OSAKA: Self = Self::new(4, 5, 3, 3, 3);
+
+    /// Versions of [`EthereumHardfork::Amsterdam`] payloads.
+    ///
+    /// These follow the draft specification and may still change.
+    pub const AMSTERDAM: Self = Self::new(5, 6, 4, 4, 4);
+
+    /// Creates a new [`EngineApiVersions`].
+    pub const fn new(
+        new_payload: u8,
+        get_payload: u8,
+        forkchoice_updated: u8,
+        execution_payload: u8,
+        payload_attributes: u8,
+    ) -> Self {
+        Self { new_payload, get_payload, forkchoice_updated, execution_payload, payload_attributes }
+    }
+
+    /// Returns the preferred version of the given method.
+    pub const fn method_version(&self, method: EngineApiMethod) -> u8 {
+        match method {
+            EngineApiMethod::NewPayload => self.new_payload,
+            EngineApiMethod::GetPayload => self.get_payload,
+            EngineApiMethod::ForkchoiceUpdated => self.forkchoice_updated,
+        }
+    }
+
+    /// Returns `true` if the given version of the method accepts payloads of these versions.
+    ///
+    /// This is the preferred version, and additionally the V2 methods for
+    /// [`EthereumHardfork::Paris`] payloads, which accept pre-Shanghai payloads.
+    pub const fn is_valid(&self, method: EngineApiMethod, version: u8) -> bool {
+        let preferred = self.method_version(method);
+        version == preferred || (preferred == 1 && version == 2)
+    }
+}
+
+impl EthereumHardfork {
+    /// Returns the Engine API versions for payloads of the hardfork, or `None` if it predates the
+    /// merge.
+    ///
+    /// Blob parameter only forks use the versions of [`EthereumHardfork::Osaka`].
+    pub const fn engine_api_versions(&self) -> Option<EngineApiVersions> {
+        match self {
+            Self::Paris => Some(EngineApiVersions::PARIS),
+            Self::Shanghai => Some(EngineApiVersions::SHANGHAI),
+            Self::Cancun => Some(EngineApiVersions::CANCUN),
+            Self::Prague => Some(EngineApiVersions::PRAGUE),
+            Self::Osaka | Self::Bpo1 | Self::Bpo2 | Self::Bpo3 | Self::Bpo4 | Self::Bpo5 => {
+                Some(EngineApiVersions::OSAKA)
+            }
+            Self::Amsterdam => Some(EngineApiVersions::AMSTERDAM),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EthereumChainHardforks, EthereumHardforks, ethereum::*};
+
+    #[test]
+    fn engine_api_versions() {
+        let forks = EthereumChainHardforks::sepolia();
+        assert_eq!(forks.engine_api_versions(0), EngineApiVersions::PARIS);
+        assert_eq!(
+            forks.engine_api_versions(SEPOLIA_SHANGHAI_TIMESTAMP),
+            EngineApiVersions::SHANGHAI
+        );
+        assert_eq!(forks.engine_api_versions(SEPOLIA_CANCUN_TIMESTAMP), EngineApiVersions::CANCUN);
+        assert_eq!(forks.engine_api_versions(SEPOLIA_PRAGUE_TIMESTAMP), EngineApiVersions::PRAGUE);
+        assert_eq!(forks.engine_api_versions(SEPOLIA_OSAKA_TIMESTAMP), EngineApiVersions::OSAKA);
+        assert_eq!(forks.engine_api_versions(SEPOLIA_BPO2_TIMESTAMP), EngineApiVersions::OSAKA);
+        assert_eq!(EthereumHardfork::London.engine_api_versions(), None);
+
+        let prague = EngineApiVersions::PRAGUE;
+        assert!(prague.is_valid(EngineApiMethod::NewPayload, 4));
+        assert!(!prague.is_valid(EngineApiMethod::NewPayload, 3));
+        assert!(prague.is_valid(EngineApiMethod::ForkchoiceUpdated, 3));
+
+        let paris = EngineApiVersions::PARIS;
+        assert!(paris.is_valid(EngineApiMethod::GetPayload, 1));
+        assert!(paris.is_valid(EngineApiMethod::GetPayload, 2));
+        assert!(!paris.is_valid(EngineApiMethod::GetPayload, 3));
+
+        assert_eq!(EngineApiMethod::ForkchoiceUpdated.name(), "engine_forkchoiceUpdated");
+    }
+}
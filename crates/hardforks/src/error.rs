@@ -21,3 +21,200 @@ impl core::fmt::Display for ParseHardforkError {
 }
 
 impl core::error::Error for ParseHardforkError {}
+
+/// Error type for parsing a [`ForkCondition`](crate::ForkCondition) from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseForkConditionError {
+    /// The string is neither `never` nor of the form `<kind>:<value>` with a known kind.
+    UnknownKind(alloc::string::String),
+    /// The value of a condition can't be parsed.
+    InvalidValue {
+        /// The kind of the condition, e.g. `timestamp`.
+        kind: &'static str,
+        /// The invalid value.
+        value: alloc::string::String,
+    },
+}
+
+impl core::fmt::Display for ParseForkConditionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownKind(condition) => write!(
+                f,
+                "unknown fork condition `{condition}`, expected `block:<number>`, \
+                 `timestamp:<seconds>`, `tentative:<seconds>`, `ttd:<difficulty>@<block>` or \
+                 `never`"
+            ),
+            Self::InvalidValue { kind, value } => write!(f, "invalid {kind} value `{value}`"),
+        }
+    }
+}
+
+impl core::error::Error for ParseForkConditionError {}
+
+/// Error type for converting a geth-style genesis `config` into a hardfork schedule.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenesisConfigError {
+    /// The config contains a fork activation key which is not supported.
+    UnknownKey(alloc::string::String),
+    /// Two keys which activate the same hardfork have different values.
+    MismatchedActivation {
+        /// The first key.
+        key: &'static str,
+        /// The key contradicting `key`.
+        other_key: &'static str,
+    },
+    /// A key is required by another key of the config, but is missing.
+    MissingKey {
+        /// The missing key.
+        key: &'static str,
+        /// The key requiring `key` to be set.
+        required_by: &'static str,
+    },
+    /// A hardfork supporting blobs is scheduled without an entry in the `blobSchedule`.
+    MissingBlobParams(crate::EthereumHardfork),
+    /// The block at which a non-zero `terminalTotalDifficulty` was reached is not known for the
+    /// chain of the config.
+    UnknownMergeBlock,
+    /// A hardfork is scheduled with a condition which can't be expressed by its config key.
+    UnsupportedCondition {
+        /// The config key of the hardfork.
+        key: &'static str,
+        /// The unsupported condition.
+        condition: crate::ForkCondition,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl core::fmt::Display for GenesisConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownKey(key) => write!(f, "unknown fork activation key `{key}`"),
+            Self::MismatchedActivation { key, other_key } => {
+                write!(f, "`{key}` and `{other_key}` must have the same value")
+            }
+            Self::MissingKey { key, required_by } => {
+                write!(f, "`{key}` must be set when `{required_by}` is set")
+            }
+            Self::MissingBlobParams(fork) => {
+                write!(f, "`blobSchedule` has no entry for `{}`", fork.name().to_lowercase())
+            }
+            Self::UnknownMergeBlock => {
+                f.write_str("the merge block of a non-zero `terminalTotalDifficulty` is unknown")
+            }
+            Self::UnsupportedCondition { key, condition } => {
+                write!(f, "`{key}` can't be activated by {condition}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl core::error::Error for GenesisConfigError {}
+
+/// Error type for hardfork schedules which are not consistent, listing every violation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForkScheduleError<F = crate::EthereumHardfork> {
+    violations: alloc::vec::Vec<ForkScheduleViolation<F>>,
+}
+
+impl<F> ForkScheduleError<F> {
+    /// Creates a new schedule error with the given violations.
+    pub const fn new(violations: alloc::vec::Vec<ForkScheduleViolation<F>>) -> Self {
+        Self { violations }
+    }
+
+    /// Returns the violations of the schedule.
+    pub fn violations(&self) -> &[ForkScheduleViolation<F>] {
+        &self.violations
+    }
+
+    /// Consumes the error, returning the violations of the schedule.
+    pub fn into_violations(self) -> alloc::vec::Vec<ForkScheduleViolation<F>> {
+        self.violations
+    }
+}
+
+impl<F: core::fmt::Display> core::fmt::Display for ForkScheduleError<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid hardfork schedule")?;
+        for (i, violation) in self.violations.iter().enumerate() {
+            write!(f, "{} {violation}", if i == 0 { ":" } else { ";" })?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: core::fmt::Debug + core::fmt::Display> core::error::Error for ForkScheduleError<F> {}
+
+/// A single inconsistency of a schedule of hardforks of type `F`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForkScheduleViolation<F = crate::EthereumHardfork> {
+    /// A hardfork is listed more than once.
+    Duplicate {
+        /// The duplicated hardfork.
+        fork: F,
+    },
+    /// A hardfork is activated by a kind of condition it doesn't support, e.g. a block number for
+    /// a hardfork activated by timestamp.
+    InvalidCondition {
+        /// The hardfork.
+        fork: F,
+        /// The unsupported condition.
+        condition: crate::ForkCondition,
+    },
+    /// A hardfork activates before a hardfork preceding it, or by block number after a hardfork
+    /// preceding it activates by timestamp.
+    OutOfOrder {
+        /// The hardfork activating too early.
+        fork: F,
+        /// The activation of `fork`.
+        condition: crate::ForkCondition,
+        /// The preceding hardfork.
+        previous: F,
+        /// The activation of `previous`.
+        previous_condition: crate::ForkCondition,
+    },
+    /// A hardfork is scheduled while a hardfork it builds upon is not.
+    MissingFork {
+        /// The scheduled hardfork.
+        fork: F,
+        /// The hardfork `fork` builds upon.
+        required: F,
+    },
+    /// A hardfork activates before the Ethereum hardfork it implies, e.g. an OP hardfork before
+    /// the Ethereum hardfork it includes.
+    PrecedesImpliedFork {
+        /// The implying hardfork.
+        fork: F,
+        /// The activation of `fork`.
+        condition: crate::ForkCondition,
+        /// The implied hardfork.
+        implied: crate::EthereumHardfork,
+        /// The activation of `implied`.
+        implied_condition: crate::ForkCondition,
+    },
+}
+
+impl<F: core::fmt::Display> core::fmt::Display for ForkScheduleViolation<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Duplicate { fork } => write!(f, "{fork} is listed more than once"),
+            Self::InvalidCondition { fork, condition } => {
+                write!(f, "{fork} can't be activated by {condition}")
+            }
+            Self::OutOfOrder { fork, condition, previous, previous_condition } => {
+                write!(f, "{fork} ({condition}) activates before {previous} ({previous_condition})")
+            }
+            Self::MissingFork { fork, required } => {
+                write!(f, "{fork} is scheduled but {required} is not")
+            }
+            Self::PrecedesImpliedFork { fork, condition, implied, implied_condition } => write!(
+                f,
+                "{fork} ({condition}) activates before the implied {implied} \
+                 ({implied_condition})"
+            ),
+        }
+    }
+}
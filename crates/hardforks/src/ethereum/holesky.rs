@@ -23,3 +23,6 @@ pub const HOLESKY_BPO1_TIMESTAMP: u64 = 1759800000;
 
 /// BPO2 hardfork activation timestamp
 pub const HOLESKY_BPO2_TIMESTAMP: u64 = 1760389824;
+
+/// Beacon chain genesis timestamp of holesky is 1695902400.
+pub const HOLESKY_BEACON_GENESIS_TIMESTAMP: u64 = 1_695_902_400;
@@ -13,3 +13,6 @@ pub const HOODI_BPO1_TIMESTAMP: u64 = 1762365720;
 
 /// BPO2 hardfork activation timestamp
 pub const HOODI_BPO2_TIMESTAMP: u64 = 1762955544;
+
+/// Beacon chain genesis timestamp of hoodi is 1742213400.
+pub const HOODI_BEACON_GENESIS_TIMESTAMP: u64 = 1_742_213_400;
@@ -82,3 +82,6 @@ pub const MAINNET_SHANGHAI_TIMESTAMP: u64 = 1_681_338_455;
 pub const MAINNET_CANCUN_TIMESTAMP: u64 = 1_710_338_135;
 /// Prague hard fork activation timestamp is 1746612311.
 pub const MAINNET_PRAGUE_TIMESTAMP: u64 = 1_746_612_311;
+
+/// Beacon chain genesis timestamp of mainnet is 1606824023.
+pub const MAINNET_BEACON_GENESIS_TIMESTAMP: u64 = 1_606_824_023;
@@ -35,3 +35,6 @@ pub const SEPOLIA_BPO1_TIMESTAMP: u64 = 1761017184;
 
 /// BPO2 hardfork activation timestamp
 pub const SEPOLIA_BPO2_TIMESTAMP: u64 = 1761607008;
+
+/// Beacon chain genesis timestamp of sepolia is 1655733600.
+pub const SEPOLIA_BEACON_GENESIS_TIMESTAMP: u64 = 1_655_733_600;
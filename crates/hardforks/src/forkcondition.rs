@@ -1,7 +1,24 @@
+use crate::ParseForkConditionError;
+use alloy_eip2124::{ForkFilterKey, Head};
 use alloy_primitives::{BlockNumber, U256};
+use core::{cmp::Ordering, fmt, str::FromStr};
 
 /// The condition at which a fork is activated.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+///
+/// Conditions are ordered by kind, block before TTD before timestamp based ones, then by their
+/// activation. [`ForkCondition::Tentative`] activations are ordered by their planned timestamp,
+/// right after a [`ForkCondition::Timestamp`] at the same time, and [`ForkCondition::Never`] comes
+/// last.
+///
+/// [`ForkCondition::Tentative`] activations are never considered active until confirmed, see
+/// [`ForkCondition::confirm`].
+///
+/// Conditions are displayed as `block:1150000`, `timestamp:1710338135`, `tentative:1761677592`,
+/// `ttd:58750000000000000000000@15537394` or `never`, and parsed back with [`FromStr`]. The
+/// `fork_block` of a [`ForkCondition::TTD`] is appended as `/1735371` if set. The alternate flag
+/// (`{:#}`) renders timestamps in RFC 3339 format instead, e.g. `timestamp:2024-03-13T13:55:35Z`,
+/// and timestamps are parsed both as seconds and in RFC 3339 format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ForkCondition {
     /// The fork is activated after a certain block.
@@ -26,6 +43,8 @@ pub enum ForkCondition {
     },
     /// The fork is activated after a specific timestamp.
     Timestamp(u64),
+    /// The fork is planned to activate after a specific timestamp, which is not final yet.
+    Tentative(u64),
     /// The fork is never activated
     #[default]
     Never,
@@ -53,6 +72,34 @@ impl ForkCondition {
         matches!(self, Self::Block(_))
     }
 
+    /// Returns true if the fork condition is tentative.
+    pub const fn is_tentative(&self) -> bool {
+        matches!(self, Self::Tentative(_))
+    }
+
+    /// Returns true if the fork has a final activation, i.e. the condition is neither
+    /// [`ForkCondition::Tentative`] nor [`ForkCondition::Never`].
+    pub const fn is_confirmed(&self) -> bool {
+        !matches!(self, Self::Tentative(_) | Self::Never)
+    }
+
+    /// Returns the planned activation timestamp of the fork condition, if it is tentative.
+    pub const fn tentative_timestamp(&self) -> Option<u64> {
+        match self {
+            Self::Tentative(timestamp) => Some(*timestamp),
+            _ => None,
+        }
+    }
+
+    /// Turns a [`ForkCondition::Tentative`] activation into the final timestamp based one, and
+    /// returns any other condition as is.
+    pub const fn confirm(self) -> Self {
+        match self {
+            Self::Tentative(timestamp) => Self::Timestamp(timestamp),
+            condition => condition,
+        }
+    }
+
     /// Checks whether the fork condition is satisfied at the given block.
     ///
     /// This will return true if the block number is equal or greater than the activation block of:
@@ -67,9 +114,14 @@ impl ForkCondition {
 
     /// Checks if the given block is the first block that satisfies the fork condition.
     ///
-    /// This will return false for any condition that is not block based.
+    /// This will return true if the block number is equal to the activation block of:
+    /// - [`ForkCondition::Block`]
+    /// - [`ForkCondition::TTD`]
+    ///
+    /// For timestamp conditions, this will always return false.
     pub const fn transitions_at_block(&self, current_block: BlockNumber) -> bool {
-        matches!(self, Self::Block(block) if current_block == *block)
+        matches!(self, Self::Block(block)
+        | Self::TTD { activation_block_number: block, .. } if current_block == *block)
     }
 
     /// Checks whether the fork condition is satisfied at the given total difficulty and difficulty
@@ -86,6 +138,16 @@ impl ForkCondition {
             if ttd.saturating_sub(difficulty) >= *total_difficulty)
     }
 
+    /// Checks if the block with the given total difficulty and difficulty, whose parent has the
+    /// given difficulty, is the first block that satisfies the fork condition according to
+    /// [`ForkCondition::active_at_ttd`], i.e. the child of the terminal proof-of-work block.
+    ///
+    /// This will return false for any condition that is not TTD-based.
+    pub fn transitions_at_ttd(&self, ttd: U256, difficulty: U256, parent_difficulty: U256) -> bool {
+        self.active_at_ttd(ttd, difficulty)
+            && !self.active_at_ttd(ttd.saturating_sub(difficulty), parent_difficulty)
+    }
+
     /// Checks whether the fork condition is satisfied at the given timestamp.
     ///
     /// This will return false for any condition that is not timestamp-based.
@@ -105,6 +167,28 @@ impl ForkCondition {
         self.active_at_timestamp(timestamp) || self.active_at_block(block_number)
     }
 
+    /// Checks whether the fork condition is satisfied at the given head.
+    ///
+    /// Block based conditions are checked against the block number, timestamp based ones against
+    /// the timestamp, and [`ForkCondition::TTD`] against the total difficulty of the head if
+    /// known, or else against its activation block number.
+    pub fn active_at(&self, head: &BlockHead) -> bool {
+        match self {
+            Self::Block(block) => head.number >= *block,
+            Self::TTD { activation_block_number, total_difficulty, .. } => head
+                .total_difficulty
+                .map_or(head.number >= *activation_block_number, |td| td >= *total_difficulty),
+            Self::Timestamp(time) => head.timestamp >= *time,
+            Self::Tentative(_) | Self::Never => false,
+        }
+    }
+
+    /// Checks if `head` is the first block that satisfies the fork condition, i.e. the condition
+    /// is satisfied at `head` but not at its `parent`.
+    pub fn transitions_at(&self, head: &BlockHead, parent: &BlockHead) -> bool {
+        self.active_at(head) && !self.active_at(parent)
+    }
+
     /// Get the total terminal difficulty for this fork condition.
     ///
     /// Returns `None` for fork conditions that are not TTD based.
@@ -132,11 +216,399 @@ impl ForkCondition {
             _ => None,
         }
     }
+
+    /// Returns the [EIP-2124][eip2124] fork point of the fork condition.
+    ///
+    /// This is the block number for [`ForkCondition::Block`], the `fork_block` for
+    /// [`ForkCondition::TTD`] (if set) and the timestamp for [`ForkCondition::Timestamp`].
+    ///
+    /// [eip2124]: https://eips.ethereum.org/EIPS/eip-2124
+    pub const fn fork_filter_key(&self) -> Option<ForkFilterKey> {
+        match self {
+            Self::Block(block) | Self::TTD { fork_block: Some(block), .. } => {
+                Some(ForkFilterKey::Block(*block))
+            }
+            Self::Timestamp(timestamp) => Some(ForkFilterKey::Time(*timestamp)),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of blocks or seconds left until the fork condition is satisfied, given
+    /// the current head.
+    ///
+    /// Returns `None` if the condition is already satisfied, or never will be.
+    pub const fn remaining_until(&self, head: &BlockHead) -> Option<ForkCountdown> {
+        match self {
+            Self::Block(block) | Self::TTD { activation_block_number: block, .. }
+                if *block > head.number =>
+            {
+                Some(ForkCountdown::Blocks(*block - head.number))
+            }
+            Self::Timestamp(time) if *time > head.timestamp => {
+                Some(ForkCountdown::Seconds(*time - head.timestamp))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ForkCondition {
+    /// Position of the condition kind in the ordering.
+    const fn kind_order(&self) -> u8 {
+        match self {
+            Self::Block(_) => 0,
+            Self::TTD { .. } => 1,
+            Self::Timestamp(_) | Self::Tentative(_) => 2,
+            Self::Never => 3,
+        }
+    }
+}
+
+impl PartialOrd for ForkCondition {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ForkCondition {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Block(a), Self::Block(b)) => a.cmp(b),
+            (
+                Self::TTD { activation_block_number, fork_block, total_difficulty },
+                Self::TTD {
+                    activation_block_number: other_activation_block_number,
+                    fork_block: other_fork_block,
+                    total_difficulty: other_total_difficulty,
+                },
+            ) => (activation_block_number, fork_block, total_difficulty).cmp(&(
+                other_activation_block_number,
+                other_fork_block,
+                other_total_difficulty,
+            )),
+            (Self::Timestamp(a) | Self::Tentative(a), Self::Timestamp(b) | Self::Tentative(b)) => {
+                a.cmp(b).then_with(|| self.is_tentative().cmp(&other.is_tentative()))
+            }
+            _ => self.kind_order().cmp(&other.kind_order()),
+        }
+    }
+}
+
+impl fmt::Display for ForkCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Block(block) => write!(f, "block:{block}"),
+            Self::TTD { activation_block_number, fork_block, total_difficulty } => {
+                write!(f, "ttd:{total_difficulty}@{activation_block_number}")?;
+                if let Some(fork_block) = fork_block {
+                    write!(f, "/{fork_block}")?;
+                }
+                Ok(())
+            }
+            Self::Timestamp(timestamp) | Self::Tentative(timestamp) => {
+                let kind = if self.is_tentative() { "tentative" } else { "timestamp" };
+                if f.alternate() {
+                    write!(f, "{kind}:")?;
+                    rfc3339::format(f, *timestamp)
+                } else {
+                    write!(f, "{kind}:{timestamp}")
+                }
+            }
+            Self::Never => f.write_str("never"),
+        }
+    }
+}
+
+impl FromStr for ForkCondition {
+    type Err = ParseForkConditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "never" {
+            return Ok(Self::Never);
+        }
+
+        let invalid =
+            |kind, value: &str| ParseForkConditionError::InvalidValue { kind, value: value.into() };
+        let number = |kind, value: &str| value.parse::<u64>().map_err(|_| invalid(kind, value));
+        let timestamp = |kind, value: &str| {
+            value
+                .parse::<u64>()
+                .ok()
+                .or_else(|| rfc3339::parse(value))
+                .ok_or_else(|| invalid(kind, value))
+        };
+
+        match s.split_once(':') {
+            Some(("block", value)) => number("block", value).map(Self::Block),
+            Some(("timestamp", value)) => timestamp("timestamp", value).map(Self::Timestamp),
+            Some(("tentative", value)) => timestamp("tentative", value).map(Self::Tentative),
+            Some(("ttd", value)) => {
+                let (total_difficulty, blocks) =
+                    value.split_once('@').ok_or_else(|| invalid("ttd", value))?;
+                let (activation_block_number, fork_block) = match blocks.split_once('/') {
+                    Some((activation, fork_block)) => (activation, Some(fork_block)),
+                    None => (blocks, None),
+                };
+                Ok(Self::TTD {
+                    activation_block_number: number("ttd", activation_block_number)?,
+                    fork_block: fork_block.map(|block| number("ttd", block)).transpose()?,
+                    total_difficulty: total_difficulty
+                        .parse()
+                        .map_err(|_| invalid("ttd", total_difficulty))?,
+                })
+            }
+            _ => Err(ParseForkConditionError::UnknownKind(s.into())),
+        }
+    }
+}
+
+/// Conversion between UNIX timestamps and [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339)
+/// date-times, based on <https://howardhinnant.github.io/date_algorithms.html>.
+mod rfc3339 {
+    use core::fmt;
+
+    const SECONDS_PER_DAY: u64 = 86_400;
+
+    /// Formats the timestamp as e.g. `2024-03-13T13:55:35Z`.
+    pub(super) fn format(f: &mut fmt::Formatter<'_>, timestamp: u64) -> fmt::Result {
+        let (year, month, day) = civil_from_days(timestamp / SECONDS_PER_DAY);
+        let seconds = timestamp % SECONDS_PER_DAY;
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+            seconds / 3_600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+
+    /// Parses a date-time such as `2024-03-13T13:55:35Z` or `2024-03-13T14:55:35+01:00`.
+    ///
+    /// Fractional seconds and leap seconds are not supported.
+    pub(super) fn parse(s: &str) -> Option<u64> {
+        let (date, time) = s.split_once(['T', 't'])?;
+
+        let mut date = date.splitn(3, '-');
+        let year = digits(date.next()?, 4)?;
+        let month = digits(date.next()?, 2)?;
+        let day = digits(date.next()?, 2)?;
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        let (time, offset) = match time.strip_suffix(['Z', 'z']) {
+            Some(time) => (time, 0),
+            None => {
+                let split = time.len().checked_sub(6)?;
+                let (time, offset) = (time.get(..split)?, time.get(split..)?);
+                let (sign, offset) = match offset.strip_prefix('+') {
+                    Some(offset) => (1, offset),
+                    None => (-1, offset.strip_prefix('-')?),
+                };
+                let (hours, minutes) = offset.split_once(':')?;
+                let (hours, minutes) = (digits(hours, 2)?, digits(minutes, 2)?);
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                (time, sign * (hours * 60 + minutes) as i64 * 60)
+            }
+        };
+
+        let mut time = time.splitn(3, ':');
+        let hours = digits(time.next()?, 2)?;
+        let minutes = digits(time.next()?, 2)?;
+        let seconds = digits(time.next()?, 2)?;
+        if hours > 23 || minutes > 59 || seconds > 59 {
+            return None;
+        }
+
+        let days = days_from_civil(year, month, day)?;
+        let timestamp = days
+            .checked_mul(SECONDS_PER_DAY)?
+            .checked_add(hours * 3_600 + minutes * 60 + seconds)?;
+        u64::try_from(i64::try_from(timestamp).ok()?.checked_sub(offset)?).ok()
+    }
+
+    /// Parses a number of exactly `len` ASCII digits.
+    fn digits(s: &str, len: usize) -> Option<u64> {
+        if s.len() != len || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    }
+
+    const fn days_in_month(year: u64, month: u64) -> u64 {
+        match month {
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns the `(year, month, day)` of the given number of days since 1970-01-01.
+    const fn civil_from_days(days: u64) -> (u64, u64, u64) {
+        let days = days + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + (month <= 2) as u64;
+        (year, month, day)
+    }
+
+    /// Returns the number of days since 1970-01-01 of the given date, if it is not earlier and
+    /// does not overflow.
+    const fn days_from_civil(year: u64, month: u64, day: u64) -> Option<u64> {
+        let year = if month <= 2 {
+            match year.checked_sub(1) {
+                Some(year) => year,
+                None => return None,
+            }
+        } else {
+            year
+        };
+        let era = year / 400;
+        let year_of_era = year % 400;
+        let mp = if month > 2 { month - 3 } else { month + 9 };
+        let day_of_year = (153 * mp + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        match era.checked_mul(146_097) {
+            Some(days) => match days.checked_add(day_of_era) {
+                Some(days) => days.checked_sub(719_468),
+                None => None,
+            },
+            None => None,
+        }
+    }
+}
+
+/// A block to evaluate [`ForkCondition`]s at, see [`ForkCondition::active_at`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BlockHead {
+    /// The block number.
+    pub number: BlockNumber,
+    /// The block timestamp.
+    pub timestamp: u64,
+    /// The total difficulty of the chain up to the parent of the block, i.e. without the
+    /// difficulty of the block itself, if known.
+    ///
+    /// This is the total difficulty checked against [`ForkCondition::TTD`], see
+    /// [`ForkCondition::active_at_ttd`].
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub total_difficulty: Option<U256>,
+    /// The timestamp of the parent block, if known.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub parent_timestamp: Option<u64>,
+}
+
+impl BlockHead {
+    /// Creates a new [`BlockHead`] with the given block number and timestamp.
+    pub const fn new(number: BlockNumber, timestamp: u64) -> Self {
+        Self { number, timestamp, total_difficulty: None, parent_timestamp: None }
+    }
+
+    /// Sets the total difficulty of the chain up to the parent of the block, see
+    /// [`BlockHead::total_difficulty`].
+    pub const fn with_total_difficulty(mut self, total_difficulty: U256) -> Self {
+        self.total_difficulty = Some(total_difficulty);
+        self
+    }
+
+    /// Sets the timestamp of the parent block.
+    pub const fn with_parent_timestamp(mut self, parent_timestamp: u64) -> Self {
+        self.parent_timestamp = Some(parent_timestamp);
+        self
+    }
+
+    /// Returns the head of the parent block, if this is not the genesis block and the parent
+    /// timestamp is known.
+    ///
+    /// The total difficulty and timestamp of the grandparent are unknown.
+    pub const fn parent(&self) -> Option<Self> {
+        match (self.number.checked_sub(1), self.parent_timestamp) {
+            (Some(number), Some(timestamp)) => Some(Self::new(number, timestamp)),
+            _ => None,
+        }
+    }
+}
+
+impl From<Head> for BlockHead {
+    fn from(head: Head) -> Self {
+        Self::new(head.number, head.timestamp)
+            .with_total_difficulty(head.total_difficulty.saturating_sub(head.difficulty))
+    }
+}
+
+/// The distance to the activation of a fork, see [`ForkCondition::remaining_until`].
+///
+/// Displayed as e.g. `1200 blocks` or `3d 4h 0m 12s`, omitting leading zero units.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ForkCountdown {
+    /// The fork activates after this many blocks.
+    Blocks(u64),
+    /// The fork activates after this many seconds.
+    Seconds(u64),
+}
+
+impl fmt::Display for ForkCountdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = match *self {
+            Self::Blocks(1) => return f.write_str("1 block"),
+            Self::Blocks(blocks) => return write!(f, "{blocks} blocks"),
+            Self::Seconds(seconds) => seconds,
+        };
+
+        let units =
+            [(seconds / 86_400, 'd'), (seconds / 3_600 % 24, 'h'), (seconds / 60 % 60, 'm')];
+        for (value, unit) in units.into_iter().skip_while(|(value, _)| *value == 0) {
+            write!(f, "{value}{unit} ")?;
+        }
+        write!(f, "{}s", seconds % 60)
+    }
+}
+
+/// A fork scheduled after a given head, together with its activation and the distance to it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NextFork<H> {
+    /// The next fork.
+    pub fork: H,
+    /// The activation of the fork.
+    pub condition: ForkCondition,
+    /// The distance from the head to the activation of the fork.
+    pub remaining: ForkCountdown,
+}
+
+impl<H> NextFork<H> {
+    /// Returns the next fork of the given forks, i.e. the one which is not active at the given
+    /// head but activates soonest.
+    ///
+    /// Pending block based forks are considered to activate before timestamp based ones, and forks
+    /// activating together are resolved by their order in `forks`.
+    pub fn find(
+        forks: impl IntoIterator<Item = (H, ForkCondition)>,
+        head: &BlockHead,
+    ) -> Option<Self> {
+        forks
+            .into_iter()
+            .filter_map(|(fork, condition)| {
+                let remaining = condition.remaining_until(head)?;
+                Some(Self { fork, condition, remaining })
+            })
+            .min_by_key(|next| next.remaining)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
     use alloy_primitives::U256;
 
     #[test]
@@ -181,6 +653,59 @@ mod tests {
             !fork_condition.transitions_at_block(11),
             "The condition should not transition at a different block number"
         );
+
+        // TTD conditions transition at their activation block
+        let fork_condition = ForkCondition::TTD {
+            activation_block_number: 10,
+            fork_block: None,
+            total_difficulty: U256::from(1000),
+        };
+        assert!(fork_condition.transitions_at_block(10));
+        assert!(!fork_condition.transitions_at_block(11));
+        assert!(!ForkCondition::Timestamp(10).transitions_at_block(10));
+    }
+
+    #[test]
+    fn test_transitions_at_ttd() {
+        let fork_condition = ForkCondition::TTD {
+            activation_block_number: 10,
+            fork_block: None,
+            total_difficulty: U256::from(1000),
+        };
+
+        // (total difficulty, difficulty, parent difficulty, expected)
+        let cases = [
+            // The parent is the terminal proof-of-work block.
+            (1005, 0, 15, true),
+            (1010, 5, 15, true),
+            // The parent reaches the threshold exactly.
+            (1010, 10, 10, true),
+            // The block reaches the threshold exactly, and is the terminal proof-of-work block.
+            (1000, 10, 10, false),
+            (1005, 15, 10, false),
+            // The grandparent reaches the threshold exactly, so the parent is already active.
+            (1010, 5, 5, false),
+            (1020, 10, 5, false),
+            // Neither the block nor its parent are active.
+            (990, 10, 10, false),
+        ];
+        for (ttd, difficulty, parent_difficulty, expected) in cases {
+            assert_eq!(
+                fork_condition.transitions_at_ttd(
+                    U256::from(ttd),
+                    U256::from(difficulty),
+                    U256::from(parent_difficulty)
+                ),
+                expected,
+                "{ttd} {difficulty} {parent_difficulty}"
+            );
+        }
+
+        assert!(!ForkCondition::Block(10).transitions_at_ttd(
+            U256::from(1010),
+            U256::from(10),
+            U256::from(10)
+        ));
     }
 
     #[test]
@@ -245,4 +770,205 @@ mod tests {
             "The condition should not transition if the parent timestamp is earlier"
         );
     }
+
+    #[test]
+    fn test_active_at_head() {
+        let head = BlockHead::new(100, 1_000).with_parent_timestamp(988);
+        let parent = head.parent().unwrap();
+        assert_eq!(parent, BlockHead::new(99, 988));
+        assert_eq!(BlockHead::new(0, 0).with_parent_timestamp(0).parent(), None);
+        assert_eq!(BlockHead::new(100, 1_000).parent(), None);
+
+        // Every condition is evaluated against the matching part of the head.
+        let ttd = ForkCondition::TTD {
+            activation_block_number: 100,
+            fork_block: None,
+            total_difficulty: U256::from(10),
+        };
+        for condition in [ForkCondition::Block(100), ttd, ForkCondition::Timestamp(1_000)] {
+            assert!(condition.active_at(&head), "{condition:?}");
+            assert!(!condition.active_at(&parent), "{condition:?}");
+            assert!(condition.transitions_at(&head, &parent), "{condition:?}");
+            assert!(!condition.transitions_at(&head, &head), "{condition:?}");
+        }
+        assert!(!ForkCondition::Never.active_at(&head));
+        assert!(!ForkCondition::Timestamp(1_001).active_at(&head));
+
+        // TTD conditions use the total difficulty when known, regardless of the activation block.
+        let head = BlockHead::from(Head {
+            number: 100,
+            timestamp: 1_000,
+            difficulty: U256::from(2),
+            total_difficulty: U256::from(12),
+            ..Default::default()
+        });
+        assert_eq!(head, BlockHead::new(100, 1_000).with_total_difficulty(U256::from(10)));
+        let terminal = BlockHead::new(99, 988).with_total_difficulty(U256::from(9));
+        assert!(ttd.active_at(&head));
+        assert!(!ttd.active_at(&terminal));
+        assert!(ttd.transitions_at(&head, &terminal));
+
+        let early = ForkCondition::TTD {
+            activation_block_number: 0,
+            fork_block: None,
+            total_difficulty: U256::from(11),
+        };
+        assert!(!early.active_at(&head));
+        assert!(early.active_at(&BlockHead::new(100, 1_000)));
+        assert!(early.active_at(&head.with_total_difficulty(U256::from(11))));
+        assert!(!early.transitions_at(&head, &terminal));
+    }
+
+    #[test]
+    fn test_tentative() {
+        let fork_condition = ForkCondition::Tentative(12345);
+        assert!(fork_condition.is_tentative());
+        assert!(!fork_condition.is_confirmed());
+        assert!(ForkCondition::Timestamp(12345).is_confirmed());
+        assert!(!ForkCondition::Never.is_confirmed());
+        assert_eq!(fork_condition.tentative_timestamp(), Some(12345));
+
+        // Tentative activations are never active
+        assert!(!fork_condition.active_at_timestamp(u64::MAX));
+        assert!(!fork_condition.active_at(&BlockHead::new(u64::MAX, u64::MAX)));
+        assert_eq!(fork_condition.remaining_until(&BlockHead::new(0, 0)), None);
+        assert_eq!(fork_condition.fork_filter_key(), None);
+
+        assert_eq!(fork_condition.confirm(), ForkCondition::Timestamp(12345));
+
+        // Tentative activations are ordered by their planned timestamp
+        let mut conditions = [
+            ForkCondition::Never,
+            ForkCondition::Tentative(200),
+            ForkCondition::Timestamp(300),
+            ForkCondition::Tentative(100),
+            ForkCondition::Timestamp(100),
+            ForkCondition::Block(1_000),
+            ForkCondition::Timestamp(50),
+        ];
+        conditions.sort();
+        assert_eq!(
+            conditions,
+            [
+                ForkCondition::Block(1_000),
+                ForkCondition::Timestamp(50),
+                ForkCondition::Timestamp(100),
+                ForkCondition::Tentative(100),
+                ForkCondition::Tentative(200),
+                ForkCondition::Timestamp(300),
+                ForkCondition::Never,
+            ]
+        );
+        assert_eq!(ForkCondition::Block(10).confirm(), ForkCondition::Block(10));
+    }
+
+    #[test]
+    fn test_remaining_until() {
+        assert_eq!(
+            ForkCondition::Block(10).remaining_until(&BlockHead::new(4, 0)),
+            Some(ForkCountdown::Blocks(6))
+        );
+        assert_eq!(ForkCondition::Block(10).remaining_until(&BlockHead::new(10, 0)), None);
+        assert_eq!(
+            ForkCondition::Timestamp(100).remaining_until(&BlockHead::new(0, 40)),
+            Some(ForkCountdown::Seconds(60))
+        );
+        assert_eq!(ForkCondition::Timestamp(100).remaining_until(&BlockHead::new(200, 100)), None);
+        assert_eq!(ForkCondition::Never.remaining_until(&BlockHead::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_fork_countdown_display() {
+        assert_eq!(ForkCountdown::Blocks(1).to_string(), "1 block");
+        assert_eq!(ForkCountdown::Blocks(42).to_string(), "42 blocks");
+        assert_eq!(ForkCountdown::Seconds(0).to_string(), "0s");
+        assert_eq!(ForkCountdown::Seconds(3_725).to_string(), "1h 2m 5s");
+        assert_eq!(ForkCountdown::Seconds(90_000).to_string(), "1d 1h 0m 0s");
+    }
+
+    #[test]
+    fn test_next_fork() {
+        let forks = [
+            ("a", ForkCondition::Timestamp(300)),
+            ("b", ForkCondition::Timestamp(200)),
+            ("c", ForkCondition::Timestamp(200)),
+            ("d", ForkCondition::Block(50)),
+            ("e", ForkCondition::Never),
+        ];
+        let next = |block_number, timestamp| {
+            NextFork::find(forks, &BlockHead::new(block_number, timestamp))
+                .map(|next| (next.fork, next.remaining))
+        };
+
+        assert_eq!(next(10, 100), Some(("d", ForkCountdown::Blocks(40))));
+        assert_eq!(next(50, 100), Some(("b", ForkCountdown::Seconds(100))));
+        assert_eq!(next(50, 200), Some(("a", ForkCountdown::Seconds(100))));
+        assert_eq!(next(50, 300), None);
+    }
+
+    #[test]
+    fn test_display_from_str() {
+        let ttd = ForkCondition::TTD {
+            activation_block_number: 15_537_394,
+            fork_block: None,
+            total_difficulty: U256::from(58_750_000_000_000_000_000_000_u128),
+        };
+        for (condition, s) in [
+            (ForkCondition::Block(1_150_000), "block:1150000"),
+            (ForkCondition::Timestamp(1_710_338_135), "timestamp:1710338135"),
+            (ForkCondition::Tentative(1_761_677_592), "tentative:1761677592"),
+            (ttd, "ttd:58750000000000000000000@15537394"),
+            (
+                ForkCondition::TTD {
+                    activation_block_number: 15_537_394,
+                    fork_block: Some(15_537_393),
+                    total_difficulty: U256::from(58_750_000_000_000_000_000_000_u128),
+                },
+                "ttd:58750000000000000000000@15537394/15537393",
+            ),
+            (ForkCondition::Never, "never"),
+        ] {
+            assert_eq!(condition.to_string(), s);
+            assert_eq!(s.parse::<ForkCondition>(), Ok(condition));
+            assert_eq!(alloc::format!("{condition:#}").parse::<ForkCondition>(), Ok(condition));
+        }
+
+        let cancun = ForkCondition::Timestamp(crate::ethereum::MAINNET_CANCUN_TIMESTAMP);
+        assert_eq!(alloc::format!("{cancun:#}"), "timestamp:2024-03-13T13:55:35Z");
+        assert_eq!("timestamp:2024-03-13T14:55:35+01:00".parse(), Ok(cancun));
+        assert_eq!("timestamp:2024-03-13T08:25:35-05:30".parse(), Ok(cancun));
+        assert_eq!(
+            alloc::format!("{:#}", ForkCondition::Tentative(0)),
+            "tentative:1970-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            "timestamp:2024-02-29T00:00:00Z".parse(),
+            Ok(ForkCondition::Timestamp(1_709_164_800))
+        );
+
+        assert_eq!(
+            "osaka:1".parse::<ForkCondition>(),
+            Err(ParseForkConditionError::UnknownKind("osaka:1".to_string()))
+        );
+        assert_eq!(
+            "block:0x10".parse::<ForkCondition>(),
+            Err(ParseForkConditionError::InvalidValue { kind: "block", value: "0x10".to_string() })
+        );
+        for invalid in [
+            "1150000",
+            "ttd:100",
+            "ttd:abc@1",
+            "timestamp:2023-02-29T00:00:00Z",
+            "timestamp:2024-03-13T13:55:35",
+            "timestamp:2024-03-13T24:00:00Z",
+            "timestamp:1969-12-31T23:59:59Z",
+            "timestamp:0000-01-01T00:00:00Z",
+            "timestamp:99999999999999999-01-01T00:00:00Z",
+            "timestamp:2024-003-013T13:55:35Z",
+            "timestamp:2024-03-13T13:55:35\u{e9}1:00",
+            "timestamp:2024-03-13T13:55:35\u{e9}01:00",
+        ] {
+            assert!(invalid.parse::<ForkCondition>().is_err(), "{invalid}");
+        }
+    }
 }
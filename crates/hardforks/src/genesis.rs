@@ -0,0 +1,694 @@
+//! Hardfork schedules from geth-style `genesis.json` files.
+
+use crate::{
+    BlobSchedule, EthereumChainHardforks, EthereumHardfork, EthereumHardforks, ForkCondition,
+    GenesisConfigError,
+};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use alloy_chains::Chain;
+use alloy_primitives::U256;
+use serde::de::IgnoredAny;
+
+/// The hardfork activations of the `config` section of a geth-style `genesis.json`.
+///
+/// Fields that don't describe a hardfork activation (e.g. `ethash` or `depositContractAddress`)
+/// are ignored. Use [`EthereumChainHardforks::from_genesis_config`] to turn it into a hardfork
+/// schedule, and [`GenesisForkConfig::from_hardforks`] for the reverse.
+///
+/// ```
+/// use alloy_hardforks::{EthereumChainHardforks, EthereumHardforks, GenesisForkConfig};
+///
+/// let config: GenesisForkConfig = serde_json::from_str(
+///     r#"{
+///         "chainId": 1337,
+///         "londonBlock": 0,
+///         "terminalTotalDifficulty": 0,
+///         "shanghaiTime": 0,
+///         "cancunTime": 1700000000,
+///         "blobSchedule": {
+///             "cancun": { "target": 3, "max": 6, "baseFeeUpdateFraction": 3338477 }
+///         }
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// let forks = EthereumChainHardforks::from_genesis_config(&config).unwrap();
+/// assert!(forks.is_cancun_active_at_timestamp(1700000000));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenesisForkConfig {
+    /// The chain ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+    /// [`EthereumHardfork::Homestead`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homestead_block: Option<u64>,
+    /// [`EthereumHardfork::Dao`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dao_fork_block: Option<u64>,
+    /// [`EthereumHardfork::Tangerine`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eip150_block: Option<u64>,
+    /// [`EthereumHardfork::SpuriousDragon`] activation block, must match `eip158Block`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eip155_block: Option<u64>,
+    /// [`EthereumHardfork::SpuriousDragon`] activation block, must match `eip155Block`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eip158_block: Option<u64>,
+    /// [`EthereumHardfork::Byzantium`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byzantium_block: Option<u64>,
+    /// [`EthereumHardfork::Constantinople`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constantinople_block: Option<u64>,
+    /// [`EthereumHardfork::Petersburg`] activation block.
+    ///
+    /// Like in geth, this defaults to `constantinopleBlock` if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub petersburg_block: Option<u64>,
+    /// [`EthereumHardfork::Istanbul`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub istanbul_block: Option<u64>,
+    /// [`EthereumHardfork::MuirGlacier`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub muir_glacier_block: Option<u64>,
+    /// [`EthereumHardfork::Berlin`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub berlin_block: Option<u64>,
+    /// [`EthereumHardfork::London`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub london_block: Option<u64>,
+    /// [`EthereumHardfork::ArrowGlacier`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arrow_glacier_block: Option<u64>,
+    /// [`EthereumHardfork::GrayGlacier`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gray_glacier_block: Option<u64>,
+    /// The merge netsplit block, advertised as the `fork_block` of [`EthereumHardfork::Paris`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_netsplit_block: Option<u64>,
+    /// The terminal total difficulty of [`EthereumHardfork::Paris`].
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "ttd")]
+    pub terminal_total_difficulty: Option<U256>,
+    /// [`EthereumHardfork::Shanghai`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shanghai_time: Option<u64>,
+    /// [`EthereumHardfork::Cancun`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancun_time: Option<u64>,
+    /// [`EthereumHardfork::Prague`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prague_time: Option<u64>,
+    /// [`EthereumHardfork::Osaka`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub osaka_time: Option<u64>,
+    /// [`EthereumHardfork::Bpo1`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bpo1_time: Option<u64>,
+    /// [`EthereumHardfork::Bpo2`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bpo2_time: Option<u64>,
+    /// [`EthereumHardfork::Bpo3`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bpo3_time: Option<u64>,
+    /// [`EthereumHardfork::Bpo4`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bpo4_time: Option<u64>,
+    /// [`EthereumHardfork::Bpo5`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bpo5_time: Option<u64>,
+    /// [`EthereumHardfork::Amsterdam`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amsterdam_time: Option<u64>,
+    /// [EIP-7840](https://eips.ethereum.org/EIPS/eip-7840) blob parameters per hardfork.
+    #[serde(default, skip_serializing_if = "BlobSchedule::is_empty")]
+    pub blob_schedule: BlobSchedule,
+    /// Keys that are not known by this type, kept to detect unsupported fork activations.
+    #[serde(flatten, skip_serializing)]
+    other: BTreeMap<String, IgnoredAny>,
+}
+
+impl GenesisForkConfig {
+    /// Creates the genesis `config` activations of the given hardfork schedule.
+    ///
+    /// The `chainId` is left unset. Since geth doesn't store it, the activation block of a
+    /// [`ForkCondition::TTD`] is not part of the config.
+    ///
+    /// Returns an error if a hardfork is scheduled with a condition geth can't express, e.g. a
+    /// block number for [`EthereumHardfork::Shanghai`].
+    pub fn from_hardforks(hardforks: &impl EthereumHardforks) -> Result<Self, GenesisConfigError> {
+        use EthereumHardfork::*;
+
+        let block =
+            |fork, key| Self::block_activation(key, hardforks.ethereum_fork_activation(fork));
+        let time =
+            |fork, key| Self::timestamp_activation(key, hardforks.ethereum_fork_activation(fork));
+
+        let (terminal_total_difficulty, merge_netsplit_block) =
+            match hardforks.ethereum_fork_activation(Paris) {
+                ForkCondition::TTD { total_difficulty, fork_block, .. } => {
+                    (Some(total_difficulty), fork_block)
+                }
+                ForkCondition::Never => (None, None),
+                condition => {
+                    return Err(GenesisConfigError::UnsupportedCondition {
+                        key: "terminalTotalDifficulty",
+                        condition,
+                    });
+                }
+            };
+        let spurious_dragon_block = block(SpuriousDragon, "eip155Block")?;
+
+        Ok(Self {
+            chain_id: None,
+            homestead_block: block(Homestead, "homesteadBlock")?,
+            dao_fork_block: block(Dao, "daoForkBlock")?,
+            eip150_block: block(Tangerine, "eip150Block")?,
+            eip155_block: spurious_dragon_block,
+            eip158_block: spurious_dragon_block,
+            byzantium_block: block(Byzantium, "byzantiumBlock")?,
+            constantinople_block: block(Constantinople, "constantinopleBlock")?,
+            petersburg_block: block(Petersburg, "petersburgBlock")?,
+            istanbul_block: block(Istanbul, "istanbulBlock")?,
+            muir_glacier_block: block(MuirGlacier, "muirGlacierBlock")?,
+            berlin_block: block(Berlin, "berlinBlock")?,
+            london_block: block(London, "londonBlock")?,
+            arrow_glacier_block: block(ArrowGlacier, "arrowGlacierBlock")?,
+            gray_glacier_block: block(GrayGlacier, "grayGlacierBlock")?,
+            merge_netsplit_block,
+            terminal_total_difficulty,
+            shanghai_time: time(Shanghai, "shanghaiTime")?,
+            cancun_time: time(Cancun, "cancunTime")?,
+            prague_time: time(Prague, "pragueTime")?,
+            osaka_time: time(Osaka, "osakaTime")?,
+            bpo1_time: time(Bpo1, "bpo1Time")?,
+            bpo2_time: time(Bpo2, "bpo2Time")?,
+            bpo3_time: time(Bpo3, "bpo3Time")?,
+            bpo4_time: time(Bpo4, "bpo4Time")?,
+            bpo5_time: time(Bpo5, "bpo5Time")?,
+            amsterdam_time: time(Amsterdam, "amsterdamTime")?,
+            blob_schedule: BlobSchedule::new(EthereumHardfork::VARIANTS.iter().filter_map(
+                |fork| {
+                    let scheduled = hardforks.ethereum_fork_activation(*fork).is_confirmed();
+                    let params = hardforks.blob_params(*fork).filter(|_| scheduled)?;
+                    Some((*fork, params))
+                },
+            )),
+            other: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the activation block of a block based genesis `config` key.
+    ///
+    /// Returns an error if the condition is neither block based nor [`ForkCondition::Never`].
+    // Not public API, shared with `alloy-op-hardforks`.
+    #[doc(hidden)]
+    pub const fn block_activation(
+        key: &'static str,
+        condition: ForkCondition,
+    ) -> Result<Option<u64>, GenesisConfigError> {
+        match condition {
+            ForkCondition::Block(block) => Ok(Some(block)),
+            ForkCondition::Never => Ok(None),
+            condition => Err(GenesisConfigError::UnsupportedCondition { key, condition }),
+        }
+    }
+
+    /// Returns the activation timestamp of a timestamp based genesis `config` key.
+    ///
+    /// [`ForkCondition::Tentative`] activations are not final and left out. Returns an error if
+    /// the condition is neither timestamp based nor [`ForkCondition::Never`].
+    // Not public API, shared with `alloy-op-hardforks`.
+    #[doc(hidden)]
+    pub const fn timestamp_activation(
+        key: &'static str,
+        condition: ForkCondition,
+    ) -> Result<Option<u64>, GenesisConfigError> {
+        match condition {
+            ForkCondition::Timestamp(timestamp) => Ok(Some(timestamp)),
+            ForkCondition::Tentative(_) | ForkCondition::Never => Ok(None),
+            condition => Err(GenesisConfigError::UnsupportedCondition { key, condition }),
+        }
+    }
+
+    /// Returns the keys of the config which look like fork activations (i.e. end with `Block` or
+    /// `Time`) but are not known.
+    pub fn unknown_fork_keys(&self) -> impl Iterator<Item = &str> {
+        self.other
+            .keys()
+            .map(String::as_str)
+            .filter(|key| key.ends_with("Block") || key.ends_with("Time"))
+    }
+}
+
+impl EthereumChainHardforks {
+    /// Creates a new [`EthereumChainHardforks`] from the `config` section of a geth-style
+    /// `genesis.json`.
+    ///
+    /// [`EthereumHardfork::Frontier`] is always activated at genesis. If `terminalTotalDifficulty`
+    /// is set, the activation block of [`EthereumHardfork::Paris`] is taken from the known chains
+    /// (see [`EthereumHardfork::activation_block`]), and is `0` for a zero terminal total
+    /// difficulty. Otherwise it is unknown, and an error is returned. `mergeNetsplitBlock` becomes
+    /// its `fork_block`.
+    ///
+    /// The `blobSchedule` becomes the [`BlobSchedule`] of the chain.
+    ///
+    /// Returns an error if the config contains unknown fork activation keys, keys which
+    /// contradict each other, or if a scheduled hardfork supporting blobs has no entry in the
+    /// `blobSchedule`.
+    pub fn from_genesis_config(config: &GenesisForkConfig) -> Result<Self, GenesisConfigError> {
+        use EthereumHardfork::*;
+
+        if let Some(key) = config.unknown_fork_keys().next() {
+            return Err(GenesisConfigError::UnknownKey(key.into()));
+        }
+
+        let spurious_dragon_block = match (config.eip155_block, config.eip158_block) {
+            (Some(eip155), Some(eip158)) if eip155 != eip158 => {
+                return Err(GenesisConfigError::MismatchedActivation {
+                    key: "eip155Block",
+                    other_key: "eip158Block",
+                });
+            }
+            (eip155, eip158) => eip155.or(eip158),
+        };
+
+        let paris = match (config.terminal_total_difficulty, config.merge_netsplit_block) {
+            (Some(total_difficulty), fork_block) => {
+                let activation_block_number = config
+                    .chain_id
+                    .and_then(|id| Paris.activation_block(Chain::from_id(id)))
+                    .or(total_difficulty.is_zero().then_some(0))
+                    .ok_or(GenesisConfigError::UnknownMergeBlock)?;
+                Some(ForkCondition::TTD { activation_block_number, fork_block, total_difficulty })
+            }
+            (None, Some(_)) => {
+                return Err(GenesisConfigError::MissingKey {
+                    key: "terminalTotalDifficulty",
+                    required_by: "mergeNetsplitBlock",
+                });
+            }
+            (None, None) => None,
+        };
+
+        let blocks = [
+            (Homestead, config.homestead_block),
+            (Dao, config.dao_fork_block),
+            (Tangerine, config.eip150_block),
+            (SpuriousDragon, spurious_dragon_block),
+            (Byzantium, config.byzantium_block),
+            (Constantinople, config.constantinople_block),
+            (Petersburg, config.petersburg_block.or(config.constantinople_block)),
+            (Istanbul, config.istanbul_block),
+            (MuirGlacier, config.muir_glacier_block),
+            (Berlin, config.berlin_block),
+            (London, config.london_block),
+            (ArrowGlacier, config.arrow_glacier_block),
+            (GrayGlacier, config.gray_glacier_block),
+        ];
+        let timestamps = [
+            (Shanghai, config.shanghai_time),
+            (Cancun, config.cancun_time),
+            (Prague, config.prague_time),
+            (Osaka, config.osaka_time),
+            (Bpo1, config.bpo1_time),
+            (Bpo2, config.bpo2_time),
+            (Bpo3, config.bpo3_time),
+            (Bpo4, config.bpo4_time),
+            (Bpo5, config.bpo5_time),
+            (Amsterdam, config.amsterdam_time),
+        ];
+
+        let mut forks = Vec::with_capacity(EthereumHardfork::VARIANTS.len());
+        forks.push((Frontier, ForkCondition::ZERO_BLOCK));
+        forks.extend(
+            blocks
+                .into_iter()
+                .filter_map(|(fork, block)| Some((fork, ForkCondition::Block(block?)))),
+        );
+        forks.extend(paris.map(|paris| (Paris, paris)));
+        forks.extend(
+            timestamps
+                .into_iter()
+                .filter_map(|(fork, time)| Some((fork, ForkCondition::Timestamp(time?)))),
+        );
+
+        if let Some((fork, _)) = forks
+            .iter()
+            .find(|(fork, _)| fork.supports_blobs() && config.blob_schedule.get(*fork).is_none())
+        {
+            return Err(GenesisConfigError::MissingBlobParams(*fork));
+        }
+
+        Ok(Self::new(forks).with_blob_schedule(config.blob_schedule.clone()))
+    }
+}
+
+/// (De)serialization of the terminal total difficulty.
+///
+/// geth reads and writes the terminal total difficulty as a plain JSON number, which may not fit
+/// into a `u64`. Such numbers are only representable as floats by most JSON deserializers, so
+/// the ones which can't be represented exactly are rejected, and written as strings instead.
+mod ttd {
+    use alloc::string::ToString;
+    use alloy_primitives::U256;
+    use core::{fmt, str::FromStr};
+    use serde::{Deserializer, Serialize, Serializer, de};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Option<U256>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        // geth only reads bare numbers, only values beyond `u128` are written as strings.
+        match value.map(u128::try_from) {
+            Some(Ok(value)) => serializer.serialize_u128(value),
+            _ => value.map(|value| value.to_string()).serialize(serializer),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<U256>, D::Error> {
+        deserializer.deserialize_option(OptionalTtdVisitor)
+    }
+
+    struct OptionalTtdVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionalTtdVisitor {
+        type Value = Option<U256>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a terminal total difficulty")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(TtdVisitor).map(Some)
+        }
+    }
+
+    struct TtdVisitor;
+
+    impl de::Visitor<'_> for TtdVisitor {
+        type Value = U256;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a terminal total difficulty")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(U256::from(v))
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+            Ok(U256::from(v))
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            // Only integers below 2^53 are guaranteed to be represented exactly.
+            const MAX_EXACT: f64 = (1u64 << f64::MANTISSA_DIGITS) as f64;
+
+            // The mainnet terminal total difficulty, which geth writes as a bare number.
+            #[expect(clippy::float_cmp)]
+            if v == 5.875e22 {
+                Ok(U256::from(58_750_000_000_000_000_000_000_u128))
+            } else if (0.0..MAX_EXACT).contains(&v) && v == (v as u64) as f64 {
+                Ok(U256::from(v as u64))
+            } else {
+                Err(E::custom(alloc::format!(
+                    "terminal total difficulty {v} cannot be represented exactly, use a string"
+                )))
+            }
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            U256::from_str(v).map_err(E::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlobParams;
+    use alloc::string::ToString;
+
+    const MAINNET_CONFIG: &str = r#"{
+        "chainId": 1,
+        "homesteadBlock": 1150000,
+        "daoForkBlock": 1920000,
+        "daoForkSupport": true,
+        "eip150Block": 2463000,
+        "eip155Block": 2675000,
+        "eip158Block": 2675000,
+        "byzantiumBlock": 4370000,
+        "constantinopleBlock": 7280000,
+        "petersburgBlock": 7280000,
+        "istanbulBlock": 9069000,
+        "muirGlacierBlock": 9200000,
+        "berlinBlock": 12244000,
+        "londonBlock": 12965000,
+        "arrowGlacierBlock": 13773000,
+        "grayGlacierBlock": 15050000,
+        "terminalTotalDifficulty": 58750000000000000000000,
+        "shanghaiTime": 1681338455,
+        "cancunTime": 1710338135,
+        "pragueTime": 1746612311,
+        "blobSchedule": {
+            "cancun": { "target": 3, "max": 6, "baseFeeUpdateFraction": 3338477 },
+            "prague": { "target": 6, "max": 9, "baseFeeUpdateFraction": 5007716 }
+        },
+        "depositContractAddress": "0x00000000219ab540356cbb839cbe05303d7705fa",
+        "ethash": {}
+    }"#;
+
+    fn parse(json: &str) -> Result<EthereumChainHardforks, GenesisConfigError> {
+        EthereumChainHardforks::from_genesis_config(&serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn mainnet_genesis_config() {
+        let forks = parse(MAINNET_CONFIG).unwrap();
+        let mainnet = EthereumChainHardforks::mainnet();
+
+        for fork in EthereumHardfork::VARIANTS {
+            assert_eq!(
+                forks.ethereum_fork_activation(*fork),
+                mainnet.ethereum_fork_activation(*fork),
+                "{fork}"
+            );
+        }
+    }
+
+    #[test]
+    fn devnet_genesis_config() {
+        let forks = parse(
+            r#"{
+                "chainId": 1337,
+                "homesteadBlock": 0,
+                "eip150Block": 0,
+                "eip158Block": 0,
+                "byzantiumBlock": 0,
+                "constantinopleBlock": 0,
+                "istanbulBlock": 0,
+                "berlinBlock": 0,
+                "londonBlock": 0,
+                "mergeNetsplitBlock": 0,
+                "terminalTotalDifficulty": "0x0",
+                "shanghaiTime": 0,
+                "cancunTime": 0,
+                "pragueTime": 0,
+                "osakaTime": 1000,
+                "bpo1Time": 2000,
+                "blobSchedule": {
+                    "cancun": { "target": 3, "max": 6, "baseFeeUpdateFraction": 3338477 },
+                    "prague": { "target": 6, "max": 9, "baseFeeUpdateFraction": 5007716 },
+                    "osaka": { "target": 6, "max": 9, "baseFeeUpdateFraction": 5007716 },
+                    "bpo1": { "target": 48, "max": 64, "baseFeeUpdateFraction": 60000000 },
+                    "bpo3": { "target": 96, "max": 128, "baseFeeUpdateFraction": 120000000 }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::SpuriousDragon),
+            ForkCondition::ZERO_BLOCK
+        );
+        // Petersburg defaults to Constantinople.
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Petersburg),
+            ForkCondition::ZERO_BLOCK
+        );
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Paris),
+            ForkCondition::TTD {
+                activation_block_number: 0,
+                fork_block: Some(0),
+                total_difficulty: U256::ZERO
+            }
+        );
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Bpo1),
+            ForkCondition::Timestamp(2000)
+        );
+        assert_eq!(forks.ethereum_fork_activation(EthereumHardfork::Dao), ForkCondition::Never);
+        assert_eq!(forks.ethereum_fork_activation(EthereumHardfork::Bpo2), ForkCondition::Never);
+
+        let bpo1 = BlobParams { target: 48, max: 64, base_fee_update_fraction: 60_000_000 };
+        assert_eq!(forks.blob_params_at_timestamp(1999), Some(BlobParams::PRAGUE));
+        assert_eq!(forks.blob_params_at_timestamp(2000), Some(bpo1));
+        // Entries of unscheduled forks are kept.
+        assert!(forks.blob_schedule().get(EthereumHardfork::Bpo3).is_some());
+    }
+
+    #[test]
+    fn genesis_config_roundtrip() {
+        for (forks, chain) in [
+            (EthereumChainHardforks::mainnet(), Chain::mainnet()),
+            (EthereumChainHardforks::sepolia(), Chain::sepolia()),
+            (EthereumChainHardforks::holesky(), Chain::holesky()),
+            (EthereumChainHardforks::hoodi(), Chain::hoodi()),
+            (EthereumChainHardforks::devnet(), Chain::dev()),
+        ] {
+            let mut config = GenesisForkConfig::from_hardforks(&forks).unwrap();
+            config.chain_id = Some(chain.id());
+            let json = serde_json::to_string(&config).unwrap();
+            let parsed: GenesisForkConfig = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, config);
+
+            let roundtrip = EthereumChainHardforks::from_genesis_config(&parsed).unwrap();
+            assert_eq!(roundtrip.blob_schedule(), forks.blob_schedule());
+            for fork in EthereumHardfork::VARIANTS {
+                match forks.ethereum_fork_activation(*fork) {
+                    // The activation block is not part of the genesis config.
+                    ForkCondition::TTD { fork_block, total_difficulty, .. } => assert!(matches!(
+                        roundtrip.ethereum_fork_activation(*fork),
+                        ForkCondition::TTD { fork_block: f, total_difficulty: td, .. }
+                            if f == fork_block && td == total_difficulty
+                    )),
+                    condition => {
+                        assert_eq!(roundtrip.ethereum_fork_activation(*fork), condition, "{fork}")
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mainnet_genesis_config_json() {
+        let config = GenesisForkConfig::from_hardforks(&EthereumChainHardforks::mainnet()).unwrap();
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert!(json.contains(r#""eip155Block":2675000,"eip158Block":2675000"#));
+        assert!(json.contains(r#""terminalTotalDifficulty":58750000000000000000000"#));
+        assert!(json.contains(r#""cancunTime":1710338135"#));
+        assert!(!json.contains("mergeNetsplitBlock"));
+        assert!(!json.contains("osakaTime"));
+        assert!(json.contains(
+            r#""blobSchedule":{"cancun":{"target":3,"max":6,"baseFeeUpdateFraction":3338477},"#
+        ));
+
+        // Values beyond `u128` are written as strings.
+        let config: GenesisForkConfig = serde_json::from_str(&alloc::format!(
+            r#"{{"terminalTotalDifficulty":"{}"}}"#,
+            U256::MAX
+        ))
+        .unwrap();
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(&alloc::format!(r#""terminalTotalDifficulty":"{}""#, U256::MAX)));
+        assert_eq!(serde_json::from_str::<GenesisForkConfig>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn blob_schedule_errors() {
+        assert_eq!(
+            parse(
+                r#"{
+                    "shanghaiTime": 0,
+                    "cancunTime": 0,
+                    "pragueTime": 0,
+                    "blobSchedule": {
+                        "cancun": { "target": 3, "max": 6, "baseFeeUpdateFraction": 3338477 }
+                    }
+                }"#
+            )
+            .unwrap_err(),
+            GenesisConfigError::MissingBlobParams(EthereumHardfork::Prague)
+        );
+        assert_eq!(
+            GenesisConfigError::MissingBlobParams(EthereumHardfork::Bpo1).to_string(),
+            "`blobSchedule` has no entry for `bpo1`"
+        );
+
+        for key in ["verkle", "london"] {
+            let json = alloc::format!(
+                r#"{{"blobSchedule": {{"{key}": {{"target": 1, "max": 2, "baseFeeUpdateFraction": 3}}}}}}"#
+            );
+            assert!(serde_json::from_str::<GenesisForkConfig>(&json).is_err(), "{key}");
+        }
+    }
+
+    #[test]
+    fn genesis_config_errors() {
+        assert_eq!(
+            parse(r#"{"londonBlock": 0, "verkleTime": 0}"#).unwrap_err(),
+            GenesisConfigError::UnknownKey("verkleTime".into())
+        );
+        assert_eq!(
+            parse(r#"{"eip155Block": 10, "eip158Block": 11}"#).unwrap_err(),
+            GenesisConfigError::MismatchedActivation {
+                key: "eip155Block",
+                other_key: "eip158Block"
+            }
+        );
+        assert_eq!(
+            parse(r#"{"mergeNetsplitBlock": 10}"#).unwrap_err(),
+            GenesisConfigError::MissingKey {
+                key: "terminalTotalDifficulty",
+                required_by: "mergeNetsplitBlock"
+            }
+        );
+        // The netsplit block is not the block at which the terminal total difficulty is reached.
+        for json in [
+            r#"{"terminalTotalDifficulty": 100}"#,
+            r#"{"chainId": 1337, "mergeNetsplitBlock": 10, "terminalTotalDifficulty": 100}"#,
+        ] {
+            assert_eq!(parse(json).unwrap_err(), GenesisConfigError::UnknownMergeBlock, "{json}");
+        }
+        for ttd in ["1.5e30", "58750000000000000000001000", "9007199254740993.0"] {
+            let json = alloc::format!(r#"{{"terminalTotalDifficulty": {ttd}}}"#);
+            assert!(serde_json::from_str::<GenesisForkConfig>(&json).is_err(), "{ttd}");
+        }
+        // The mainnet terminal total difficulty as dumped by geth.
+        let config: GenesisForkConfig =
+            serde_json::from_str(r#"{"terminalTotalDifficulty": 58750000000000000000000}"#)
+                .unwrap();
+        assert_eq!(
+            config.terminal_total_difficulty,
+            Some(U256::from(58_750_000_000_000_000_000_000_u128))
+        );
+        assert_eq!(
+            GenesisForkConfig::from_hardforks(&EthereumChainHardforks::new([(
+                EthereumHardfork::Shanghai,
+                ForkCondition::Block(10)
+            )]))
+            .unwrap_err(),
+            GenesisConfigError::UnsupportedCondition {
+                key: "shanghaiTime",
+                condition: ForkCondition::Block(10)
+            }
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{
+    BlobParams, BlobSchedule, EthereumChainHardforks, EthereumHardfork, ForkCondition,
+    ForkScheduleError,
+};
+use alloc::collections::BTreeMap;
+use alloy_primitives::U256;
+use core::mem::discriminant;
+
+/// A builder for [`EthereumChainHardforks`], starting from a preset schedule.
+///
+/// ```
+/// use alloy_hardforks::{
+///     EthereumChainHardforksBuilder, EthereumHardfork, EthereumHardforks, ForkCondition,
+/// };
+///
+/// let forks = EthereumChainHardforksBuilder::mainnet()
+///     .with_fork(EthereumHardfork::Osaka, ForkCondition::Timestamp(1_800_000_600))
+///     .build()
+///     .unwrap();
+/// assert!(forks.is_osaka_active_at_timestamp(1_800_000_600));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EthereumChainHardforksBuilder {
+    forks: BTreeMap<EthereumHardfork, ForkCondition>,
+    blob_schedule: BlobSchedule,
+}
+
+impl EthereumChainHardforksBuilder {
+    /// Creates a new builder without any hardforks.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new builder starting from the Mainnet schedule.
+    pub fn mainnet() -> Self {
+        Self::from_forks(EthereumHardfork::mainnet()).with_blob_schedule(BlobSchedule::mainnet())
+    }
+
+    /// Creates a new builder starting from the Sepolia schedule.
+    pub fn sepolia() -> Self {
+        Self::from_forks(EthereumHardfork::sepolia()).with_blob_schedule(BlobSchedule::sepolia())
+    }
+
+    /// Creates a new builder starting from the Holesky schedule.
+    pub fn holesky() -> Self {
+        Self::from_forks(EthereumHardfork::holesky()).with_blob_schedule(BlobSchedule::holesky())
+    }
+
+    /// Creates a new builder starting from the Hoodi schedule.
+    pub fn hoodi() -> Self {
+        Self::from_forks(EthereumHardfork::hoodi()).with_blob_schedule(BlobSchedule::hoodi())
+    }
+
+    /// Creates a new builder starting from the Devnet schedule.
+    pub fn devnet() -> Self {
+        Self::from_forks(EthereumHardfork::devnet()).with_blob_schedule(BlobSchedule::devnet())
+    }
+
+    /// Creates a new builder starting from the given list of forks, with an empty
+    /// [`BlobSchedule`].
+    pub fn from_forks(forks: impl IntoIterator<Item = (EthereumHardfork, ForkCondition)>) -> Self {
+        Self { forks: forks.into_iter().collect(), blob_schedule: BlobSchedule::default() }
+    }
+
+    /// Sets the [`BlobSchedule`], replacing the one of the preset.
+    pub fn with_blob_schedule(mut self, blob_schedule: BlobSchedule) -> Self {
+        self.blob_schedule = blob_schedule;
+        self
+    }
+
+    /// Sets the blob parameters of `fork`, replacing any previous ones.
+    pub fn with_blob_params(mut self, fork: EthereumHardfork, params: BlobParams) -> Self {
+        self.blob_schedule.insert(fork, params);
+        self
+    }
+
+    /// Sets the activation of `fork`, replacing any previous one.
+    pub fn with_fork(mut self, fork: EthereumHardfork, condition: ForkCondition) -> Self {
+        self.forks.insert(fork, condition);
+        self
+    }
+
+    /// Removes `fork` from the schedule.
+    pub fn without_fork(mut self, fork: EthereumHardfork) -> Self {
+        self.forks.remove(&fork);
+        self
+    }
+
+    /// Activates `fork` at `condition`, making sure every hardfork preceding it is active by then.
+    ///
+    /// Preceding hardforks keep their activation if it is not later than `condition`. Otherwise
+    /// they are activated together with the hardfork preceding them, or at genesis if there is
+    /// none they can activate with. Hardforks following `fork` are left untouched.
+    ///
+    /// If `fork` can't be activated by `condition`, e.g. a block number for
+    /// [`EthereumHardfork::Shanghai`], the preceding hardforks are left untouched as well and
+    /// [`Self::build`] returns an error.
+    pub fn activate_all_until(mut self, fork: EthereumHardfork, condition: ForkCondition) -> Self {
+        if !fork.supports_condition(&condition) {
+            return self.with_fork(fork, condition);
+        }
+        let mut previous = None;
+        for preceding in EthereumHardfork::VARIANTS.iter().copied().take_while(|f| *f < fork) {
+            let existing = self.forks.get(&preceding).copied().unwrap_or_default();
+            let activation = if activates_by(existing, condition)
+                && previous.is_none_or(|previous| activates_by(previous, existing))
+            {
+                existing
+            } else {
+                // Activate with the preceding hardfork if it uses the same kind of condition.
+                let genesis = genesis_condition(preceding);
+                previous
+                    .filter(|previous| discriminant(previous) == discriminant(&genesis))
+                    .unwrap_or(genesis)
+            };
+            self.forks.insert(preceding, activation);
+            previous = Some(activation);
+        }
+        self.with_fork(fork, condition)
+    }
+
+    /// Shifts every timestamp based activation, including tentative ones, by `delta` seconds,
+    /// saturating at the numeric bounds.
+    ///
+    /// Useful to move a schedule relative to the genesis timestamp of a new network.
+    pub fn shift_timestamps(mut self, delta: i64) -> Self {
+        for condition in self.forks.values_mut() {
+            if let ForkCondition::Timestamp(timestamp) | ForkCondition::Tentative(timestamp) =
+                condition
+            {
+                *timestamp = timestamp.saturating_add_signed(delta);
+            }
+        }
+        self
+    }
+
+    /// Builds the [`EthereumChainHardforks`], checking that they form a consistent schedule.
+    ///
+    /// See [`EthereumChainHardforks::try_new`].
+    pub fn build(self) -> Result<EthereumChainHardforks, ForkScheduleError> {
+        Ok(EthereumChainHardforks::try_new(self.forks)?.with_blob_schedule(self.blob_schedule))
+    }
+}
+
+/// Returns true if a hardfork activated at `existing` is active once `condition` is satisfied.
+const fn activates_by(existing: ForkCondition, condition: ForkCondition) -> bool {
+    use ForkCondition::*;
+
+    match (existing, condition) {
+        (
+            Block(existing) | TTD { activation_block_number: existing, .. },
+            Block(block) | TTD { activation_block_number: block, .. },
+        ) => existing <= block,
+        (Block(_) | TTD { .. }, Timestamp(_) | Tentative(_)) => true,
+        (Timestamp(existing), Timestamp(timestamp) | Tentative(timestamp))
+        | (Tentative(existing), Tentative(timestamp)) => existing <= timestamp,
+        _ => false,
+    }
+}
+
+/// Returns the condition activating `fork` at genesis.
+const fn genesis_condition(fork: EthereumHardfork) -> ForkCondition {
+    match fork {
+        EthereumHardfork::Paris => ForkCondition::TTD {
+            activation_block_number: 0,
+            fork_block: None,
+            total_difficulty: U256::ZERO,
+        },
+        fork if fork.supports_condition(&ForkCondition::ZERO_BLOCK) => ForkCondition::ZERO_BLOCK,
+        _ => ForkCondition::ZERO_TIMESTAMP,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EthereumHardforks, ForkScheduleViolation};
+
+    #[test]
+    fn mainnet_with_osaka() {
+        let forks = EthereumChainHardforksBuilder::mainnet()
+            .with_fork(EthereumHardfork::Osaka, ForkCondition::Timestamp(1_800_000_000))
+            .without_fork(EthereumHardfork::Dao)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Osaka),
+            ForkCondition::Timestamp(1_800_000_000)
+        );
+        assert_eq!(forks.ethereum_fork_activation(EthereumHardfork::Dao), ForkCondition::Never);
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Prague),
+            EthereumChainHardforks::mainnet().ethereum_fork_activation(EthereumHardfork::Prague)
+        );
+    }
+
+    #[test]
+    fn blob_schedule() {
+        let params = BlobParams { target: 20, max: 30, base_fee_update_fraction: 20_000_000 };
+        let forks = EthereumChainHardforksBuilder::hoodi()
+            .with_fork(EthereumHardfork::Bpo3, ForkCondition::Timestamp(2_000_000_000))
+            .with_blob_params(EthereumHardfork::Bpo3, params)
+            .build()
+            .unwrap();
+
+        assert_eq!(forks.blob_schedule().get(EthereumHardfork::Bpo2), Some(BlobParams::BPO2));
+        assert_eq!(forks.blob_params_at_timestamp(2_000_000_000), Some(params));
+        assert!(EthereumChainHardforksBuilder::empty().build().unwrap().blob_schedule().is_empty());
+    }
+
+    #[test]
+    fn activate_all_until() {
+        let forks = EthereumChainHardforksBuilder::empty()
+            .activate_all_until(EthereumHardfork::Cancun, ForkCondition::Timestamp(100))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::London),
+            ForkCondition::ZERO_BLOCK
+        );
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Paris),
+            genesis_condition(EthereumHardfork::Paris)
+        );
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Shanghai),
+            ForkCondition::ZERO_TIMESTAMP
+        );
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Cancun),
+            ForkCondition::Timestamp(100)
+        );
+        assert_eq!(forks.ethereum_fork_activation(EthereumHardfork::Prague), ForkCondition::Never);
+
+        let forks = EthereumChainHardforksBuilder::empty()
+            .activate_all_until(EthereumHardfork::Berlin, ForkCondition::Block(10))
+            .build()
+            .unwrap();
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Frontier),
+            ForkCondition::ZERO_BLOCK
+        );
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Berlin),
+            ForkCondition::Block(10)
+        );
+        assert_eq!(forks.ethereum_fork_activation(EthereumHardfork::London), ForkCondition::Never);
+
+        // Earlier activations are kept, later ones are moved to the activation preceding them.
+        let forks = EthereumChainHardforksBuilder::mainnet()
+            .activate_all_until(EthereumHardfork::Osaka, ForkCondition::Timestamp(1_700_000_000))
+            .build()
+            .unwrap();
+        let mainnet = EthereumChainHardforks::mainnet();
+        for fork in [EthereumHardfork::London, EthereumHardfork::Paris, EthereumHardfork::Shanghai]
+        {
+            assert_eq!(
+                forks.ethereum_fork_activation(fork),
+                mainnet.ethereum_fork_activation(fork),
+                "{fork}"
+            );
+        }
+        for fork in [EthereumHardfork::Cancun, EthereumHardfork::Prague] {
+            assert_eq!(
+                forks.ethereum_fork_activation(fork),
+                mainnet.ethereum_fork_activation(EthereumHardfork::Shanghai),
+                "{fork}"
+            );
+        }
+
+        let err = EthereumChainHardforksBuilder::mainnet()
+            .activate_all_until(EthereumHardfork::Shanghai, ForkCondition::Block(10))
+            .build()
+            .unwrap_err();
+        assert!(err.violations().contains(&ForkScheduleViolation::InvalidCondition {
+            fork: EthereumHardfork::Shanghai,
+            condition: ForkCondition::Block(10)
+        }));
+    }
+
+    #[test]
+    fn shift_timestamps() {
+        let genesis_timestamp = 1_700_000_000;
+        let forks = EthereumChainHardforksBuilder::devnet()
+            .with_fork(EthereumHardfork::Bpo2, ForkCondition::Timestamp(600))
+            .shift_timestamps(genesis_timestamp)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Osaka),
+            ForkCondition::Timestamp(genesis_timestamp as u64)
+        );
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Bpo2),
+            ForkCondition::Timestamp(genesis_timestamp as u64 + 600)
+        );
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::London),
+            ForkCondition::ZERO_BLOCK
+        );
+
+        let forks = EthereumChainHardforksBuilder::devnet().shift_timestamps(-1).build().unwrap();
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Osaka),
+            ForkCondition::ZERO_TIMESTAMP
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use crate::{
-    ForkCondition,
+    BlobParams, BlobSchedule, BlockHead, EngineApiVersions, ForkChange, ForkCondition, ForkFilter,
+    ForkFilterKey, ForkId, ForkScheduleError, ForkScheduleViolation, Head, HeaderField, NextFork,
+    Precompile,
     arbitrum::{mainnet::*, sepolia::*},
     ethereum::{holesky::*, hoodi::*, mainnet::*, sepolia::*},
-    hardfork,
+    hardfork, schedule,
 };
 use alloc::vec::Vec;
 use alloy_chains::{Chain, NamedChain};
-use alloy_primitives::U256;
+use alloy_primitives::{Address, B256, U256};
 
 hardfork!(
     /// The name of an Ethereum hardfork.
@@ -657,6 +659,119 @@ impl EthereumHardfork {
             _ => None,
         }
     }
+
+    /// Returns the numbers of the EIPs introduced by the hardfork on the execution layer.
+    ///
+    /// Meta EIPs are not included. The scope of [`EthereumHardfork::Amsterdam`] is not final yet,
+    /// and it has no EIPs listed.
+    pub const fn eips(&self) -> &'static [u32] {
+        match self {
+            Self::Frontier
+            | Self::Dao
+            | Self::Petersburg
+            | Self::Bpo1
+            | Self::Bpo2
+            | Self::Bpo3
+            | Self::Bpo4
+            | Self::Bpo5
+            | Self::Amsterdam => &[],
+            Self::Homestead => &[2, 7, 8],
+            Self::Tangerine => &[150],
+            Self::SpuriousDragon => &[155, 160, 161, 170],
+            Self::Byzantium => &[100, 140, 196, 197, 198, 211, 214, 649, 658],
+            Self::Constantinople => &[145, 1014, 1052, 1234, 1283],
+            Self::Istanbul => &[152, 1108, 1344, 1884, 2028, 2200],
+            Self::MuirGlacier => &[2384],
+            Self::Berlin => &[2565, 2718, 2929, 2930],
+            Self::London => &[1559, 3198, 3529, 3541, 3554],
+            Self::ArrowGlacier => &[4345],
+            Self::GrayGlacier => &[5133],
+            Self::Paris => &[3675, 4399],
+            Self::Shanghai => &[3651, 3855, 3860, 4895, 6049],
+            Self::Cancun => &[1153, 4788, 4844, 5656, 6780, 7516],
+            Self::Prague => &[2537, 2935, 6110, 7002, 7251, 7623, 7685, 7691, 7702],
+            Self::Osaka => &[7594, 7823, 7825, 7883, 7918, 7934, 7939, 7951],
+        }
+    }
+
+    /// Returns the numbers of the EIPs removed by the hardfork, e.g. [EIP-1283] by
+    /// [`EthereumHardfork::Petersburg`].
+    ///
+    /// [EIP-1283]: https://eips.ethereum.org/EIPS/eip-1283
+    pub const fn removed_eips(&self) -> &'static [u32] {
+        match self {
+            Self::Petersburg => &[1283],
+            _ => &[],
+        }
+    }
+
+    /// Returns `true` if blocks of the hardfork can carry blobs, i.e. it is
+    /// [`EthereumHardfork::Cancun`] or later.
+    pub const fn supports_blobs(&self) -> bool {
+        *self as usize >= Self::Cancun as usize
+    }
+
+    /// Returns the blob parameters defined for the hardfork by its EIPs, regardless of the chain.
+    ///
+    /// Returns `None` for hardforks which don't support blobs, and for blob parameter only forks,
+    /// whose parameters are chain specific.
+    pub const fn blob_params(&self) -> Option<BlobParams> {
+        match self {
+            Self::Cancun => Some(BlobParams::CANCUN),
+            Self::Prague | Self::Osaka => Some(BlobParams::PRAGUE),
+            _ => None,
+        }
+    }
+
+    /// Returns the hardfork introducing the given EIP, see [`EthereumHardfork::eips`].
+    pub fn from_eip(eip: u32) -> Option<Self> {
+        Self::VARIANTS.iter().copied().find(|fork| fork.eips().contains(&eip))
+    }
+
+    /// Returns the hardfork this hardfork builds upon, which has to be scheduled as well.
+    ///
+    /// Difficulty bomb delays and blob parameter only forks are optional, and not required by the
+    /// hardforks following them.
+    const fn required_fork(&self) -> Option<Self> {
+        Some(match self {
+            Self::Frontier => return None,
+            Self::Homestead => Self::Frontier,
+            Self::Dao | Self::Tangerine => Self::Homestead,
+            Self::SpuriousDragon => Self::Tangerine,
+            Self::Byzantium => Self::SpuriousDragon,
+            Self::Constantinople => Self::Byzantium,
+            Self::Petersburg => Self::Constantinople,
+            Self::Istanbul => Self::Petersburg,
+            Self::MuirGlacier | Self::Berlin => Self::Istanbul,
+            Self::London => Self::Berlin,
+            Self::ArrowGlacier | Self::GrayGlacier | Self::Paris => Self::London,
+            Self::Shanghai => Self::Paris,
+            Self::Cancun => Self::Shanghai,
+            Self::Prague => Self::Cancun,
+            Self::Osaka => Self::Prague,
+            Self::Bpo1 | Self::Amsterdam => Self::Osaka,
+            Self::Bpo2 => Self::Bpo1,
+            Self::Bpo3 => Self::Bpo2,
+            Self::Bpo4 => Self::Bpo3,
+            Self::Bpo5 => Self::Bpo4,
+        })
+    }
+
+    /// Returns `true` if the hardfork can be activated by the given kind of condition.
+    ///
+    /// Hardforks up to [`EthereumHardfork::GrayGlacier`] are activated by block number,
+    /// [`EthereumHardfork::Paris`] by block number or total difficulty, and later hardforks by
+    /// timestamp, which may be tentative.
+    pub(crate) const fn supports_condition(&self, condition: &ForkCondition) -> bool {
+        match condition {
+            ForkCondition::Never => true,
+            ForkCondition::Block(_) => (*self as usize) <= Self::Paris as usize,
+            ForkCondition::TTD { .. } => matches!(self, Self::Paris),
+            ForkCondition::Timestamp(_) | ForkCondition::Tentative(_) => {
+                (*self as usize) > Self::Paris as usize
+            }
+        }
+    }
 }
 
 /// Helper methods for Ethereum forks.
@@ -676,6 +791,150 @@ pub trait EthereumHardforks {
         self.ethereum_fork_activation(fork).active_at_block(block_number)
     }
 
+    /// Convenience method to check if an [`EthereumHardfork`] is active at a given head, see
+    /// [`ForkCondition::active_at`].
+    fn is_ethereum_fork_active_at(&self, fork: EthereumHardfork, head: &BlockHead) -> bool {
+        self.ethereum_fork_activation(fork).active_at(head)
+    }
+
+    /// Convenience method to check if an [`EthereumHardfork`] activates at a given head, see
+    /// [`ForkCondition::transitions_at`].
+    fn ethereum_fork_transitions_at(
+        &self,
+        fork: EthereumHardfork,
+        head: &BlockHead,
+        parent: &BlockHead,
+    ) -> bool {
+        self.ethereum_fork_activation(fork).transitions_at(head, parent)
+    }
+
+    /// Returns the [`EthereumHardfork`]s active at the given head, in canonical order, see
+    /// [`ForkCondition::active_at`].
+    fn active_ethereum_forks(&self, head: &BlockHead) -> Vec<EthereumHardfork> {
+        EthereumHardfork::VARIANTS
+            .iter()
+            .copied()
+            .filter(|fork| self.is_ethereum_fork_active_at(*fork, head))
+            .collect()
+    }
+
+    /// Returns the [`EthereumHardfork`]s with a [`ForkCondition::Tentative`] activation, together
+    /// with their planned activation timestamp, in canonical order.
+    fn tentative_ethereum_forks(&self) -> Vec<(EthereumHardfork, u64)> {
+        EthereumHardfork::VARIANTS
+            .iter()
+            .copied()
+            .filter_map(|fork| {
+                Some((fork, self.ethereum_fork_activation(fork).tentative_timestamp()?))
+            })
+            .collect()
+    }
+
+    /// Returns the latest [`EthereumHardfork`] active at the given head, or `None` if no
+    /// hardfork is active.
+    fn latest_ethereum_fork(&self, head: &BlockHead) -> Option<EthereumHardfork> {
+        EthereumHardfork::VARIANTS
+            .iter()
+            .rev()
+            .copied()
+            .find(|fork| self.is_ethereum_fork_active_at(*fork, head))
+    }
+
+    /// Returns `true` if the given EIP is active at the given head, i.e. the hardfork introducing
+    /// it is active and no hardfork removing it is.
+    ///
+    /// Returns `false` for EIPs which are not part of any [`EthereumHardfork`], see
+    /// [`EthereumHardfork::eips`].
+    fn is_eip_active_at(&self, eip: u32, head: &BlockHead) -> bool {
+        let is_active = |fork: EthereumHardfork| self.is_ethereum_fork_active_at(fork, head);
+
+        EthereumHardfork::from_eip(eip).is_some_and(is_active)
+            && !EthereumHardfork::VARIANTS
+                .iter()
+                .any(|fork| fork.removed_eips().contains(&eip) && is_active(*fork))
+    }
+
+    /// Returns the [EIP-7840][eip7840] blob parameters of `fork`, if it supports blobs and its
+    /// parameters are known.
+    ///
+    /// Defaults to [`EthereumHardfork::blob_params`].
+    ///
+    /// [eip7840]: https://eips.ethereum.org/EIPS/eip-7840
+    fn blob_params(&self, fork: EthereumHardfork) -> Option<BlobParams> {
+        fork.blob_params()
+    }
+
+    /// Returns the blob parameters active at the given timestamp, i.e. the ones of the latest
+    /// active hardfork supporting blobs with known parameters.
+    fn blob_params_at_timestamp(&self, timestamp: u64) -> Option<BlobParams> {
+        EthereumHardfork::VARIANTS
+            .iter()
+            .rev()
+            .filter(|fork| {
+                fork.supports_blobs()
+                    && self.is_ethereum_fork_active_at_timestamp(**fork, timestamp)
+            })
+            .find_map(|fork| self.blob_params(*fork))
+    }
+
+    /// Returns the Engine API versions for payloads with the given timestamp, i.e. the ones of the
+    /// latest active hardfork, see [`EthereumHardfork::engine_api_versions`].
+    ///
+    /// Timestamps preceding all timestamp based hardforks get the [`EthereumHardfork::Paris`]
+    /// versions, as the Engine API is only used after the merge.
+    fn engine_api_versions(&self, timestamp: u64) -> EngineApiVersions {
+        EthereumHardfork::VARIANTS
+            .iter()
+            .rev()
+            .filter(|fork| self.is_ethereum_fork_active_at_timestamp(**fork, timestamp))
+            .find_map(|fork| fork.engine_api_versions())
+            .unwrap_or(EngineApiVersions::PARIS)
+    }
+
+    /// Returns the [`HeaderField`]s required in the header of the given block, in header order.
+    fn header_fields_at(&self, head: &BlockHead) -> Vec<HeaderField> {
+        HeaderField::VARIANTS
+            .iter()
+            .copied()
+            .filter(|field| self.is_header_field_required_at(*field, head))
+            .collect()
+    }
+
+    /// Returns `true` if the given [`HeaderField`] is required in the header of the given block,
+    /// i.e. the hardfork introducing it is active.
+    fn is_header_field_required_at(&self, field: HeaderField, head: &BlockHead) -> bool {
+        self.is_ethereum_fork_active_at(field.introduced_by(), head)
+    }
+
+    /// Retrieves the [`ForkCondition`] activating the given [`Precompile`].
+    ///
+    /// Defaults to the activation of [`Precompile::introduced_by`].
+    fn precompile_activation(&self, precompile: Precompile) -> ForkCondition {
+        self.ethereum_fork_activation(precompile.introduced_by())
+    }
+
+    /// Returns the [`Precompile`]s active at the given head, ordered by address.
+    fn precompiles_at(&self, head: &BlockHead) -> Vec<Precompile> {
+        Precompile::VARIANTS
+            .iter()
+            .copied()
+            .filter(|precompile| self.precompile_activation(*precompile).active_at(head))
+            .collect()
+    }
+
+    /// Returns `true` if the given address is an active precompile at the given head.
+    fn is_precompile_active_at(&self, address: Address, head: &BlockHead) -> bool {
+        Precompile::from_address(address)
+            .is_some_and(|precompile| self.precompile_activation(precompile).active_at(head))
+    }
+
+    /// Returns `true` if the given opcode is defined at the given head, i.e. the hardfork
+    /// introducing it is active, see [`EthereumHardfork::from_opcode`].
+    fn is_opcode_valid_at(&self, opcode: u8, head: &BlockHead) -> bool {
+        EthereumHardfork::from_opcode(opcode)
+            .is_some_and(|fork| self.is_ethereum_fork_active_at(fork, head))
+    }
+
     /// Convenience method to check if [`EthereumHardfork::Homestead`] is active at a given block
     /// number.
     fn is_homestead_active_at_block(&self, block_number: u64) -> bool {
@@ -787,6 +1046,24 @@ pub trait EthereumHardforks {
     fn is_bpo5_active_at_timestamp(&self, timestamp: u64) -> bool {
         self.is_ethereum_fork_active_at_timestamp(EthereumHardfork::Bpo5, timestamp)
     }
+
+    /// Returns the [EIP-2124][eip2124] fork points of all forks in the schedule.
+    ///
+    /// See [`ForkCondition::fork_filter_key`].
+    ///
+    /// [eip2124]: https://eips.ethereum.org/EIPS/eip-2124
+    fn fork_filter_keys(&self) -> Vec<ForkFilterKey> {
+        EthereumHardfork::VARIANTS
+            .iter()
+            .filter_map(|fork| self.ethereum_fork_activation(*fork).fork_filter_key())
+            .collect()
+    }
+
+    /// Creates a [`ForkFilter`] for validating the [`ForkId`]s of remote peers, given the genesis
+    /// of the chain and the current head.
+    fn fork_filter(&self, head: Head, genesis_hash: B256, genesis_timestamp: u64) -> ForkFilter {
+        ForkFilter::new(head, genesis_hash, genesis_timestamp, self.fork_filter_keys())
+    }
 }
 
 /// A type allowing to configure activation [`ForkCondition`]s for a given list of
@@ -794,56 +1071,202 @@ pub trait EthereumHardforks {
 #[derive(Debug, Clone)]
 pub struct EthereumChainHardforks {
     forks: Vec<(EthereumHardfork, ForkCondition)>,
+    blob_schedule: BlobSchedule,
 }
 
 impl EthereumChainHardforks {
-    /// Creates a new [`EthereumChainHardforks`] with the given list of forks.
+    /// Creates a new [`EthereumChainHardforks`] with the given list of forks, and an empty
+    /// [`BlobSchedule`].
     pub fn new(forks: impl IntoIterator<Item = (EthereumHardfork, ForkCondition)>) -> Self {
         let mut forks = forks.into_iter().collect::<Vec<_>>();
         forks.sort();
-        Self { forks }
+        Self { forks, blob_schedule: BlobSchedule::default() }
+    }
+
+    /// Sets the [`BlobSchedule`] of the chain.
+    pub fn with_blob_schedule(mut self, blob_schedule: BlobSchedule) -> Self {
+        self.blob_schedule = blob_schedule;
+        self
+    }
+
+    /// Returns the [`BlobSchedule`] of the chain.
+    pub const fn blob_schedule(&self) -> &BlobSchedule {
+        &self.blob_schedule
+    }
+
+    /// Creates a new [`EthereumChainHardforks`] with the given list of forks, checking that they
+    /// form a consistent schedule.
+    ///
+    /// Unlike [`Self::new`], returns an error listing every violation: hardforks listed more than
+    /// once, activated by the wrong kind of condition or before a preceding hardfork, and
+    /// hardforks scheduled without the hardfork they build upon (e.g. Shanghai without Paris).
+    pub fn try_new(
+        forks: impl IntoIterator<Item = (EthereumHardfork, ForkCondition)>,
+    ) -> Result<Self, ForkScheduleError> {
+        let hardforks = Self::new(forks);
+        let violations = hardforks.schedule_violations();
+        if violations.is_empty() { Ok(hardforks) } else { Err(ForkScheduleError::new(violations)) }
+    }
+
+    /// Returns the inconsistencies of the schedule, see [`Self::try_new`].
+    pub fn schedule_violations(&self) -> Vec<ForkScheduleViolation> {
+        let mut violations = Vec::new();
+
+        let mut previous = None;
+        for (fork, condition) in &self.forks {
+            if previous.replace(*fork) == Some(*fork) {
+                if violations.last() != Some(&ForkScheduleViolation::Duplicate { fork: *fork }) {
+                    violations.push(ForkScheduleViolation::Duplicate { fork: *fork });
+                }
+                continue;
+            }
+            if !fork.supports_condition(condition) {
+                violations.push(ForkScheduleViolation::InvalidCondition {
+                    fork: *fork,
+                    condition: *condition,
+                });
+            }
+            if let Some(required) = fork.required_fork() {
+                let required_condition = self.ethereum_fork_activation(required);
+                if (*condition != ForkCondition::Never
+                    && required_condition == ForkCondition::Never)
+                    || (condition.is_confirmed() && required_condition.is_tentative())
+                {
+                    violations.push(ForkScheduleViolation::MissingFork { fork: *fork, required });
+                }
+            }
+        }
+
+        violations.extend(schedule::out_of_order_violations(
+            EthereumHardfork::VARIANTS
+                .iter()
+                .map(|fork| (*fork, self.ethereum_fork_activation(*fork)))
+                .filter(|(fork, condition)| fork.supports_condition(condition)),
+        ));
+        violations
     }
 
     /// Creates a new [`EthereumChainHardforks`] with Mainnet configuration.
     pub fn mainnet() -> Self {
-        Self::new(EthereumHardfork::mainnet())
+        Self::new(EthereumHardfork::mainnet()).with_blob_schedule(BlobSchedule::mainnet())
     }
 
     /// Creates a new [`EthereumChainHardforks`] with Sepolia configuration.
     pub fn sepolia() -> Self {
-        Self::new(EthereumHardfork::sepolia())
+        Self::new(EthereumHardfork::sepolia()).with_blob_schedule(BlobSchedule::sepolia())
     }
 
     /// Creates a new [`EthereumChainHardforks`] with Holesky configuration.
     pub fn holesky() -> Self {
-        Self::new(EthereumHardfork::holesky())
+        Self::new(EthereumHardfork::holesky()).with_blob_schedule(BlobSchedule::holesky())
     }
 
     /// Creates a new [`EthereumChainHardforks`] with Hoodi configuration.
     pub fn hoodi() -> Self {
-        Self::new(EthereumHardfork::hoodi())
+        Self::new(EthereumHardfork::hoodi()).with_blob_schedule(BlobSchedule::hoodi())
     }
 
     /// Creates a new [`EthereumChainHardforks`] with Devnet configuration.
     pub fn devnet() -> Self {
-        Self::new(EthereumHardfork::devnet())
+        Self::new(EthereumHardfork::devnet()).with_blob_schedule(BlobSchedule::devnet())
+    }
+
+    /// Returns an iterator over the forks of the schedule and their activations, in canonical
+    /// order.
+    pub fn iter(&self) -> core::slice::Iter<'_, (EthereumHardfork, ForkCondition)> {
+        self.forks.iter()
+    }
+
+    /// Returns the number of forks in the schedule.
+    pub fn len(&self) -> usize {
+        self.forks.len()
+    }
+
+    /// Returns `true` if the schedule contains no forks.
+    pub fn is_empty(&self) -> bool {
+        self.forks.is_empty()
+    }
+
+    /// Returns the activation of `fork`, or `None` if it is not part of the schedule.
+    ///
+    /// Unlike [`EthereumHardforks::ethereum_fork_activation`], this distinguishes a missing fork
+    /// from one explicitly set to [`ForkCondition::Never`].
+    pub fn get(&self, fork: EthereumHardfork) -> Option<ForkCondition> {
+        let idx = self.forks.binary_search_by(|(f, _)| f.cmp(&fork)).ok()?;
+        Some(self.forks[idx].1)
+    }
+
+    /// Returns `true` if `fork` is part of the schedule.
+    pub fn contains(&self, fork: EthereumHardfork) -> bool {
+        self.get(fork).is_some()
+    }
+
+    /// Returns an iterator over the forks activated by block number and their activation blocks.
+    ///
+    /// This includes [`ForkCondition::TTD`] forks, by their activation block.
+    pub fn forks_by_block(&self) -> impl Iterator<Item = (EthereumHardfork, u64)> + '_ {
+        self.forks.iter().filter_map(|(fork, condition)| Some((*fork, condition.block_number()?)))
+    }
+
+    /// Returns an iterator over the forks activated by timestamp and their activation timestamps.
+    pub fn forks_by_timestamp(&self) -> impl Iterator<Item = (EthereumHardfork, u64)> + '_ {
+        self.forks.iter().filter_map(|(fork, condition)| Some((*fork, condition.as_timestamp()?)))
+    }
+
+    /// Compares the schedule to `other`, returning the forks added, removed or moved in `other`.
+    pub fn diff(&self, other: &Self) -> Vec<ForkChange<EthereumHardfork>> {
+        ForkChange::diff(self.iter().copied(), other.iter().copied())
+    }
+
+    /// Returns the scheduled fork activating soonest after the given head, with the number of
+    /// blocks or seconds left until its activation, see [`NextFork::find`].
+    pub fn next_fork(&self, head: &BlockHead) -> Option<NextFork<EthereumHardfork>> {
+        NextFork::find(self.forks.iter().copied(), head)
+    }
+
+    /// Computes the [EIP-2124][eip2124] [`ForkId`] of the chain at the given head, see
+    /// [`EthereumHardforks::fork_filter`].
+    ///
+    /// [eip2124]: https://eips.ethereum.org/EIPS/eip-2124
+    pub fn fork_id(&self, head: Head, genesis_hash: B256, genesis_timestamp: u64) -> ForkId {
+        self.fork_filter(head, genesis_hash, genesis_timestamp).current()
     }
 }
 
 impl EthereumHardforks for EthereumChainHardforks {
     fn ethereum_fork_activation(&self, fork: EthereumHardfork) -> ForkCondition {
-        let Ok(idx) = self.forks.binary_search_by(|(f, _)| f.cmp(&fork)) else {
-            return ForkCondition::Never;
-        };
+        self.get(fork).unwrap_or(ForkCondition::Never)
+    }
 
-        self.forks[idx].1
+    fn blob_params(&self, fork: EthereumHardfork) -> Option<BlobParams> {
+        self.blob_schedule.get(fork).or_else(|| fork.blob_params())
+    }
+}
+
+impl IntoIterator for EthereumChainHardforks {
+    type Item = (EthereumHardfork, ForkCondition);
+    type IntoIter = alloc::vec::IntoIter<(EthereumHardfork, ForkCondition)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.forks.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EthereumChainHardforks {
+    type Item = &'a (EthereumHardfork, ForkCondition);
+    type IntoIter = core::slice::Iter<'a, (EthereumHardfork, ForkCondition)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::vec::Vec;
+    use crate::{ForkCountdown, ForkHash, ValidationError};
+    use alloc::{string::ToString, vec::Vec};
+    use alloy_primitives::{b256, hex};
     use core::str::FromStr;
 
     #[test]
@@ -1054,6 +1477,310 @@ mod tests {
         }
     }
 
+    #[test]
+    fn active_ethereum_forks() {
+        let mainnet = EthereumChainHardforks::mainnet();
+        assert_eq!(
+            mainnet.latest_ethereum_fork(&BlockHead::new(0, 0)),
+            Some(EthereumHardfork::Frontier)
+        );
+        assert_eq!(
+            mainnet.latest_ethereum_fork(&BlockHead::new(
+                MAINNET_PARIS_BLOCK - 1,
+                MAINNET_PARIS_TIMESTAMP - 12
+            )),
+            Some(EthereumHardfork::GrayGlacier)
+        );
+        assert_eq!(
+            mainnet.latest_ethereum_fork(&BlockHead::new(
+                MAINNET_PARIS_BLOCK,
+                MAINNET_PARIS_TIMESTAMP
+            )),
+            Some(EthereumHardfork::Paris)
+        );
+        assert_eq!(
+            mainnet.latest_ethereum_fork(&BlockHead::new(
+                MAINNET_CANCUN_BLOCK,
+                MAINNET_CANCUN_TIMESTAMP
+            )),
+            Some(EthereumHardfork::Cancun)
+        );
+        assert_eq!(
+            mainnet
+                .active_ethereum_forks(&BlockHead::new(
+                    MAINNET_CANCUN_BLOCK,
+                    MAINNET_CANCUN_TIMESTAMP
+                ))
+                .len(),
+            17
+        );
+
+        // Sepolia skips the difficulty bomb delays after London.
+        let sepolia = EthereumChainHardforks::sepolia();
+        assert_eq!(
+            sepolia.active_ethereum_forks(&BlockHead::new(0, 0)),
+            EthereumHardfork::VARIANTS[..=EthereumHardfork::London as usize]
+        );
+        assert_eq!(
+            sepolia.latest_ethereum_fork(&BlockHead::new(SEPOLIA_PARIS_BLOCK, 0)),
+            Some(EthereumHardfork::Paris)
+        );
+
+        assert_eq!(
+            EthereumChainHardforks::devnet().latest_ethereum_fork(&BlockHead::new(0, 0)),
+            Some(EthereumHardfork::Bpo2)
+        );
+        assert_eq!(
+            EthereumChainHardforks::new([]).latest_ethereum_fork(&BlockHead::new(100, 100)),
+            None
+        );
+    }
+
+    #[test]
+    fn blob_params_at_timestamp() {
+        let mainnet = EthereumChainHardforks::mainnet();
+        assert_eq!(mainnet.blob_params_at_timestamp(MAINNET_CANCUN_TIMESTAMP - 1), None);
+        assert_eq!(
+            mainnet.blob_params_at_timestamp(MAINNET_CANCUN_TIMESTAMP),
+            Some(BlobParams::CANCUN)
+        );
+        assert_eq!(
+            mainnet.blob_params_at_timestamp(MAINNET_PRAGUE_TIMESTAMP),
+            Some(BlobParams::PRAGUE)
+        );
+
+        let hoodi = EthereumChainHardforks::hoodi();
+        assert_eq!(hoodi.blob_params_at_timestamp(0), Some(BlobParams::CANCUN));
+        assert_eq!(hoodi.blob_params_at_timestamp(HOODI_OSAKA_TIMESTAMP), Some(BlobParams::PRAGUE));
+        assert_eq!(hoodi.blob_params_at_timestamp(HOODI_BPO1_TIMESTAMP), Some(BlobParams::BPO1));
+        assert_eq!(hoodi.blob_params_at_timestamp(u64::MAX), Some(BlobParams::BPO2));
+
+        // Blob parameter only forks without known parameters keep the previous ones.
+        let custom = EthereumChainHardforks::new([
+            (EthereumHardfork::Cancun, ForkCondition::ZERO_TIMESTAMP),
+            (EthereumHardfork::Prague, ForkCondition::ZERO_TIMESTAMP),
+            (EthereumHardfork::Osaka, ForkCondition::ZERO_TIMESTAMP),
+            (EthereumHardfork::Bpo1, ForkCondition::Timestamp(100)),
+            (EthereumHardfork::Bpo2, ForkCondition::Timestamp(200)),
+        ]);
+        assert_eq!(custom.blob_params_at_timestamp(150), Some(BlobParams::PRAGUE));
+
+        let params = BlobParams { target: 20, max: 30, base_fee_update_fraction: 1 };
+        let custom =
+            custom.with_blob_schedule(BlobSchedule::new([(EthereumHardfork::Bpo2, params)]));
+        assert_eq!(custom.blob_params_at_timestamp(150), Some(BlobParams::PRAGUE));
+        assert_eq!(custom.blob_params_at_timestamp(200), Some(params));
+    }
+
+    #[test]
+    fn hardfork_eips() {
+        assert_eq!(EthereumHardfork::Cancun.eips(), [1153, 4788, 4844, 5656, 6780, 7516]);
+        assert_eq!(EthereumHardfork::from_eip(1559), Some(EthereumHardfork::London));
+        assert_eq!(EthereumHardfork::from_eip(7702), Some(EthereumHardfork::Prague));
+        assert_eq!(EthereumHardfork::from_eip(1), None);
+
+        // Every EIP is introduced by a single hardfork.
+        let mut eips =
+            EthereumHardfork::VARIANTS.iter().flat_map(|fork| fork.eips()).collect::<Vec<_>>();
+        let len = eips.len();
+        eips.sort();
+        eips.dedup();
+        assert_eq!(eips.len(), len);
+    }
+
+    #[test]
+    fn is_eip_active_at() {
+        let mainnet = EthereumChainHardforks::mainnet();
+        assert!(!mainnet.is_eip_active_at(1559, &BlockHead::new(MAINNET_LONDON_BLOCK - 1, 0)));
+        assert!(mainnet.is_eip_active_at(1559, &BlockHead::new(MAINNET_LONDON_BLOCK, 0)));
+        assert!(!mainnet.is_eip_active_at(
+            4844,
+            &BlockHead::new(MAINNET_CANCUN_BLOCK, MAINNET_CANCUN_TIMESTAMP - 1)
+        ));
+        assert!(mainnet.is_eip_active_at(
+            4844,
+            &BlockHead::new(MAINNET_CANCUN_BLOCK, MAINNET_CANCUN_TIMESTAMP)
+        ));
+        assert!(!mainnet.is_eip_active_at(7594, &BlockHead::new(u64::MAX, u64::MAX)));
+        assert!(!mainnet.is_eip_active_at(1, &BlockHead::new(u64::MAX, u64::MAX)));
+
+        // EIP-1283 was removed by Petersburg.
+        let forks = EthereumChainHardforks::new([
+            (EthereumHardfork::Constantinople, ForkCondition::Block(10)),
+            (EthereumHardfork::Petersburg, ForkCondition::Block(20)),
+        ]);
+        assert!(forks.is_eip_active_at(1283, &BlockHead::new(10, 0)));
+        assert!(!forks.is_eip_active_at(1283, &BlockHead::new(20, 0)));
+        assert!(!mainnet.is_eip_active_at(1283, &BlockHead::new(MAINNET_CONSTANTINOPLE_BLOCK, 0)));
+    }
+
+    #[test]
+    fn schedule_introspection() {
+        let mainnet = EthereumChainHardforks::mainnet();
+        assert_eq!(mainnet.len(), EthereumHardfork::mainnet().len());
+        assert!(!mainnet.is_empty());
+        assert!(EthereumChainHardforks::new([]).is_empty());
+
+        assert_eq!(
+            mainnet.get(EthereumHardfork::Cancun),
+            Some(ForkCondition::Timestamp(MAINNET_CANCUN_TIMESTAMP))
+        );
+        assert_eq!(mainnet.get(EthereumHardfork::Amsterdam), None);
+        assert!(mainnet.contains(EthereumHardfork::Dao));
+        assert!(!EthereumChainHardforks::hoodi().contains(EthereumHardfork::GrayGlacier));
+
+        let forks = mainnet.iter().map(|(fork, _)| *fork).collect::<Vec<_>>();
+        assert!(forks.is_sorted());
+        assert_eq!(forks, (&mainnet).into_iter().map(|(fork, _)| *fork).collect::<Vec<_>>());
+        assert_eq!(mainnet.clone().into_iter().collect::<Vec<_>>(), EthereumHardfork::mainnet());
+
+        let by_block = mainnet.forks_by_block().collect::<Vec<_>>();
+        assert_eq!(by_block.first(), Some(&(EthereumHardfork::Frontier, 0)));
+        assert_eq!(by_block.last(), Some(&(EthereumHardfork::Paris, MAINNET_PARIS_BLOCK)));
+        assert_eq!(
+            mainnet.forks_by_timestamp().collect::<Vec<_>>(),
+            [
+                (EthereumHardfork::Shanghai, MAINNET_SHANGHAI_TIMESTAMP),
+                (EthereumHardfork::Cancun, MAINNET_CANCUN_TIMESTAMP),
+                (EthereumHardfork::Prague, MAINNET_PRAGUE_TIMESTAMP),
+            ]
+        );
+    }
+
+    #[test]
+    fn next_fork() {
+        let hoodi = EthereumChainHardforks::hoodi();
+        let timestamp = HOODI_OSAKA_TIMESTAMP - (3 * 86_400 + 4 * 3_600);
+        let next = hoodi.next_fork(&BlockHead::new(HOODI_PRAGUE_BLOCK, timestamp)).unwrap();
+        assert_eq!(next.fork, EthereumHardfork::Osaka);
+        assert_eq!(next.condition, ForkCondition::Timestamp(HOODI_OSAKA_TIMESTAMP));
+        assert_eq!(next.remaining, ForkCountdown::Seconds(3 * 86_400 + 4 * 3_600));
+        assert_eq!(next.remaining.to_string(), "3d 4h 0m 0s");
+
+        let next =
+            EthereumChainHardforks::mainnet().next_fork(&BlockHead::new(1_000_000, 0)).unwrap();
+        assert_eq!(next.fork, EthereumHardfork::Homestead);
+        assert_eq!(next.remaining, ForkCountdown::Blocks(150_000));
+        assert_eq!(next.remaining.to_string(), "150000 blocks");
+
+        // The merge is awaited by its activation block.
+        let next =
+            EthereumChainHardforks::sepolia().next_fork(&BlockHead::new(1_000_000, 0)).unwrap();
+        assert_eq!(next.fork, EthereumHardfork::Paris);
+        assert_eq!(next.remaining, ForkCountdown::Blocks(SEPOLIA_PARIS_BLOCK - 1_000_000));
+
+        assert_eq!(
+            EthereumChainHardforks::mainnet().next_fork(&BlockHead::new(u64::MAX, u64::MAX)),
+            None
+        );
+    }
+
+    #[test]
+    fn builtin_schedules_are_valid() {
+        for forks in [
+            EthereumHardfork::mainnet().as_slice(),
+            EthereumHardfork::sepolia().as_slice(),
+            EthereumHardfork::holesky().as_slice(),
+            EthereumHardfork::hoodi().as_slice(),
+            EthereumHardfork::devnet().as_slice(),
+        ] {
+            assert_eq!(EthereumChainHardforks::try_new(forks.iter().copied()).map(|_| ()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn schedule_violations() {
+        let err = EthereumChainHardforks::try_new([
+            (EthereumHardfork::Frontier, ForkCondition::ZERO_BLOCK),
+            (EthereumHardfork::Frontier, ForkCondition::Block(1)),
+            (EthereumHardfork::Homestead, ForkCondition::Block(10)),
+            (EthereumHardfork::Tangerine, ForkCondition::Block(5)),
+            (EthereumHardfork::Shanghai, ForkCondition::Timestamp(100)),
+            (EthereumHardfork::Cancun, ForkCondition::Timestamp(50)),
+            (EthereumHardfork::Prague, ForkCondition::Block(20)),
+            (EthereumHardfork::Bpo2, ForkCondition::Timestamp(200)),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            err.violations(),
+            [
+                ForkScheduleViolation::Duplicate { fork: EthereumHardfork::Frontier },
+                ForkScheduleViolation::MissingFork {
+                    fork: EthereumHardfork::Shanghai,
+                    required: EthereumHardfork::Paris
+                },
+                ForkScheduleViolation::InvalidCondition {
+                    fork: EthereumHardfork::Prague,
+                    condition: ForkCondition::Block(20)
+                },
+                ForkScheduleViolation::MissingFork {
+                    fork: EthereumHardfork::Bpo2,
+                    required: EthereumHardfork::Bpo1
+                },
+                ForkScheduleViolation::OutOfOrder {
+                    fork: EthereumHardfork::Tangerine,
+                    condition: ForkCondition::Block(5),
+                    previous: EthereumHardfork::Homestead,
+                    previous_condition: ForkCondition::Block(10),
+                },
+                ForkScheduleViolation::OutOfOrder {
+                    fork: EthereumHardfork::Cancun,
+                    condition: ForkCondition::Timestamp(50),
+                    previous: EthereumHardfork::Shanghai,
+                    previous_condition: ForkCondition::Timestamp(100),
+                },
+            ]
+        );
+        assert!(err.to_string().starts_with(
+            "invalid hardfork schedule: Frontier is listed more than once; Shanghai is scheduled \
+             but Paris is not;"
+        ));
+    }
+
+    #[test]
+    fn object_safe() {
+        let forks: &dyn EthereumHardforks = &EthereumChainHardforks::mainnet();
+        assert!(forks.is_london_active_at_block(12_965_000));
+        assert!(forks.tentative_ethereum_forks().is_empty());
+    }
+
+    #[test]
+    fn tentative_forks() {
+        let forks = EthereumChainHardforks::try_new(
+            EthereumHardfork::sepolia()
+                .into_iter()
+                .chain([(EthereumHardfork::Amsterdam, ForkCondition::Tentative(2_000_000_000))]),
+        )
+        .unwrap();
+        assert_eq!(
+            forks.ethereum_fork_activation(EthereumHardfork::Amsterdam),
+            ForkCondition::Tentative(2_000_000_000)
+        );
+        assert!(!forks.is_amsterdam_active_at_timestamp(u64::MAX));
+        assert_eq!(
+            forks.tentative_ethereum_forks(),
+            [(EthereumHardfork::Amsterdam, 2_000_000_000)]
+        );
+        assert_eq!(forks.next_fork(&BlockHead::new(u64::MAX, SEPOLIA_BPO2_TIMESTAMP)), None);
+
+        // A confirmed fork can't follow a tentative one.
+        let violations = EthereumChainHardforks::new([
+            (EthereumHardfork::Shanghai, ForkCondition::ZERO_TIMESTAMP),
+            (EthereumHardfork::Cancun, ForkCondition::Tentative(100)),
+            (EthereumHardfork::Prague, ForkCondition::Timestamp(200)),
+        ])
+        .schedule_violations();
+        assert!(violations.contains(&ForkScheduleViolation::MissingFork {
+            fork: EthereumHardfork::Prague,
+            required: EthereumHardfork::Cancun
+        }));
+        assert!(!violations.contains(&ForkScheduleViolation::MissingFork {
+            fork: EthereumHardfork::Cancun,
+            required: EthereumHardfork::Shanghai
+        }));
+    }
+
     macro_rules! test_chain_config {
         ($modname:ident, $ts_fn:ident, $bn_fn:ident) => {
             mod $modname {
@@ -1083,4 +1810,200 @@ mod tests {
     test_chain_config!(sepolia, sepolia_activation_timestamp, sepolia_activation_block);
     test_chain_config!(holesky, holesky_activation_timestamp, holesky_activation_block);
     test_chain_config!(hoodi, hoodi_activation_timestamp, hoodi_activation_block);
+
+    const MAINNET_GENESIS_HASH: B256 =
+        b256!("d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3");
+
+    const fn head(number: u64, timestamp: u64) -> Head {
+        Head {
+            number,
+            timestamp,
+            hash: B256::ZERO,
+            difficulty: U256::ZERO,
+            total_difficulty: U256::ZERO,
+        }
+    }
+
+    const SEPOLIA_GENESIS_HASH: B256 =
+        b256!("25a5cc106eea7138acab33231d7160d69cb777ee0c2c553fcddf5138993e6dd9");
+    const SEPOLIA_GENESIS_TIMESTAMP: u64 = 1_633_267_481;
+
+    const HOLESKY_GENESIS_HASH: B256 =
+        b256!("b5f7f912443c940f21fd611f12828d75b534364ed9e95ca4e307729a4661bde4");
+    const HOLESKY_GENESIS_TIMESTAMP: u64 = 1_695_902_100;
+
+    const HOODI_GENESIS_HASH: B256 =
+        b256!("bbe312868b376a3001692a646dd2d7d1e4406380dfd86b98aa8a34d1557c971b");
+    const HOODI_GENESIS_TIMESTAMP: u64 = 1_742_212_800;
+
+    /// Asserts the fork ID of `forks` at every `(block, timestamp)` head.
+    fn assert_fork_ids(
+        forks: &EthereumChainHardforks,
+        genesis_hash: B256,
+        genesis_timestamp: u64,
+        cases: &[((u64, u64), [u8; 4], u64)],
+    ) {
+        for &((number, timestamp), hash, next) in cases {
+            let head = head(number, timestamp);
+            let expected = ForkId { hash: ForkHash(hash), next };
+            assert_eq!(
+                forks.fork_id(head, genesis_hash, genesis_timestamp),
+                expected,
+                "head {head}"
+            );
+        }
+    }
+
+    #[test]
+    fn mainnet_fork_ids() {
+        assert_fork_ids(
+            &EthereumChainHardforks::mainnet(),
+            MAINNET_GENESIS_HASH,
+            0,
+            &[
+                ((0, 0), hex!("fc64ec04"), 1_150_000),
+                ((1_149_999, 0), hex!("fc64ec04"), 1_150_000),
+                ((1_150_000, 0), hex!("97c2c34c"), 1_920_000),
+                ((1_920_000, 0), hex!("91d1f948"), 2_463_000),
+                ((2_463_000, 0), hex!("7a64da13"), 2_675_000),
+                ((2_675_000, 0), hex!("3edd5b10"), 4_370_000),
+                ((4_370_000, 0), hex!("a00bc324"), 7_280_000),
+                // Constantinople and Petersburg share the same block and are folded in once.
+                ((7_280_000, 0), hex!("668db0af"), 9_069_000),
+                ((9_069_000, 0), hex!("879d6e30"), 9_200_000),
+                ((9_200_000, 0), hex!("e029e991"), 12_244_000),
+                ((12_244_000, 0), hex!("0eb440f6"), 12_965_000),
+                ((12_965_000, 0), hex!("b715077d"), 13_773_000),
+                ((13_773_000, 0), hex!("20c327fc"), 15_050_000),
+                // Paris has no `fork_block` on mainnet and is not a fork point.
+                ((15_050_000, 0), hex!("f0afd0e3"), 1_681_338_455),
+                ((17_034_869, 1_681_338_454), hex!("f0afd0e3"), 1_681_338_455),
+                ((17_034_870, 1_681_338_455), hex!("dce96c2d"), 1_710_338_135),
+                ((19_426_587, 1_710_338_135), hex!("9f3d2254"), 1_746_612_311),
+                ((22_431_084, 1_746_612_311), hex!("c376cf8b"), 0),
+            ],
+        );
+    }
+
+    #[test]
+    fn sepolia_fork_ids() {
+        assert_fork_ids(
+            &EthereumChainHardforks::sepolia(),
+            SEPOLIA_GENESIS_HASH,
+            SEPOLIA_GENESIS_TIMESTAMP,
+            &[
+                ((0, SEPOLIA_GENESIS_TIMESTAMP), hex!("fe3366e7"), 1_735_371),
+                ((1_735_370, SEPOLIA_GENESIS_TIMESTAMP), hex!("fe3366e7"), 1_735_371),
+                // Merge netsplit block, advertised through the Paris `fork_block`.
+                ((1_735_371, SEPOLIA_GENESIS_TIMESTAMP), hex!("b96cbd13"), 1_677_557_088),
+                ((2_990_908, 1_677_557_088), hex!("f7f9bc08"), 1_706_655_072),
+                ((5_187_023, 1_706_655_072), hex!("88cf81d9"), 1_741_159_776),
+                ((7_836_331, 1_741_159_776), hex!("ed88b5fd"), 1_760_427_360),
+                ((7_836_331, 1_760_427_360), hex!("e2ae4999"), 1_761_017_184),
+                ((7_836_331, 1_761_017_184), hex!("56078a1e"), 1_761_607_008),
+                ((7_836_331, 1_761_607_008), hex!("268956b6"), 0),
+            ],
+        );
+    }
+
+    #[test]
+    fn holesky_fork_ids() {
+        assert_fork_ids(
+            &EthereumChainHardforks::holesky(),
+            HOLESKY_GENESIS_HASH,
+            HOLESKY_GENESIS_TIMESTAMP,
+            &[
+                ((0, HOLESKY_GENESIS_TIMESTAMP), hex!("c61a6098"), 1_696_000_704),
+                ((6_698, 1_696_000_704), hex!("fd4f016b"), 1_707_305_664),
+                ((894_733, 1_707_305_664), hex!("9b192ad0"), 1_740_434_112),
+                ((3_419_704, 1_740_434_112), hex!("dfbd9bed"), 1_759_308_480),
+                ((3_419_704, 1_759_308_480), hex!("783def52"), 1_759_800_000),
+                ((3_419_704, 1_759_800_000), hex!("a280a45c"), 1_760_389_824),
+                ((3_419_704, 1_760_389_824), hex!("9bc6cb31"), 0),
+            ],
+        );
+    }
+
+    #[test]
+    fn hoodi_fork_ids() {
+        assert_fork_ids(
+            &EthereumChainHardforks::hoodi(),
+            HOODI_GENESIS_HASH,
+            HOODI_GENESIS_TIMESTAMP,
+            &[
+                // Shanghai and Cancun are active at genesis and not fork points.
+                ((0, HOODI_GENESIS_TIMESTAMP), hex!("bef71d30"), 1_742_999_832),
+                ((60_412, 1_742_999_832), hex!("0929e24e"), 1_761_677_592),
+                ((60_412, 1_761_677_592), hex!("e7e0e7ff"), 1_762_365_720),
+                ((60_412, 1_762_365_720), hex!("3893353e"), 1_762_955_544),
+                ((60_412, 1_762_955_544), hex!("23aa1351"), 0),
+            ],
+        );
+    }
+
+    #[test]
+    fn mainnet_fork_filter() {
+        let forks = EthereumChainHardforks::mainnet();
+        let head = head(20_000_000, 1_710_338_135);
+        let filter = forks.fork_filter(head, MAINNET_GENESIS_HASH, 0);
+
+        // A peer that has not passed Cancun yet but announces it.
+        assert_eq!(
+            filter.validate(ForkId { hash: ForkHash(hex!("dce96c2d")), next: 1_710_338_135 }),
+            Ok(())
+        );
+        // A peer that announces a different Cancun timestamp.
+        assert!(matches!(
+            filter.validate(ForkId { hash: ForkHash(hex!("dce96c2d")), next: 1_710_338_136 }),
+            Err(ValidationError::RemoteStale { .. })
+        ));
+    }
+
+    #[test]
+    fn fork_id_skips_genesis_forks() {
+        let genesis_timestamp = 1_000;
+        let forks = EthereumChainHardforks::new([
+            (EthereumHardfork::Frontier, ForkCondition::ZERO_BLOCK),
+            (EthereumHardfork::London, ForkCondition::ZERO_BLOCK),
+            (EthereumHardfork::Shanghai, ForkCondition::ZERO_TIMESTAMP),
+            (EthereumHardfork::Cancun, ForkCondition::Timestamp(genesis_timestamp)),
+            (EthereumHardfork::Prague, ForkCondition::Timestamp(2_000)),
+            (EthereumHardfork::Osaka, ForkCondition::Timestamp(2_000)),
+        ]);
+
+        let genesis = ForkHash::from(MAINNET_GENESIS_HASH);
+        assert_eq!(
+            forks.fork_id(head(0, genesis_timestamp), MAINNET_GENESIS_HASH, genesis_timestamp),
+            ForkId { hash: genesis, next: 2_000 }
+        );
+        assert_eq!(
+            forks.fork_id(head(10, 2_000), MAINNET_GENESIS_HASH, genesis_timestamp),
+            ForkId { hash: genesis + 2_000u64, next: 0 }
+        );
+    }
+
+    #[test]
+    fn fork_id_includes_ttd_fork_block() {
+        let forks = EthereumChainHardforks::new([
+            (EthereumHardfork::London, ForkCondition::ZERO_BLOCK),
+            (
+                EthereumHardfork::Paris,
+                ForkCondition::TTD {
+                    activation_block_number: 50,
+                    fork_block: Some(100),
+                    total_difficulty: U256::from(1_000),
+                },
+            ),
+        ]);
+
+        let genesis = ForkHash::from(MAINNET_GENESIS_HASH);
+        assert_eq!(
+            forks.fork_id(head(99, 0), MAINNET_GENESIS_HASH, 0),
+            ForkId { hash: genesis, next: 100 }
+        );
+        assert_eq!(
+            forks.fork_id(head(100, 0), MAINNET_GENESIS_HASH, 0),
+            ForkId { hash: genesis + 100u64, next: 0 }
+        );
+    }
 }
@@ -3,6 +3,9 @@ mod macros;
 mod ethereum;
 pub use ethereum::*;
 
+mod builder;
+pub use builder::*;
+
 use alloc::boxed::Box;
 use core::{
     any::Any,
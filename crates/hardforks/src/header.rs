@@ -0,0 +1,114 @@
+//! Block header fields introduced by hardforks.
+
+use crate::EthereumHardfork;
+use core::fmt;
+
+/// An optional block header field, required from the hardfork introducing it onwards.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum HeaderField {
+    /// `baseFeePerGas`, introduced by [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559).
+    BaseFeePerGas,
+    /// `withdrawalsRoot`, introduced by [EIP-4895](https://eips.ethereum.org/EIPS/eip-4895).
+    WithdrawalsRoot,
+    /// `blobGasUsed`, introduced by [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+    BlobGasUsed,
+    /// `excessBlobGas`, introduced by [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+    ExcessBlobGas,
+    /// `parentBeaconBlockRoot`, introduced by [EIP-4788](https://eips.ethereum.org/EIPS/eip-4788).
+    ParentBeaconBlockRoot,
+    /// `requestsHash`, introduced by [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685).
+    RequestsHash,
+}
+
+impl HeaderField {
+    /// All header fields, in the order they appear in the header.
+    pub const VARIANTS: &'static [Self] = &[
+        Self::BaseFeePerGas,
+        Self::WithdrawalsRoot,
+        Self::BlobGasUsed,
+        Self::ExcessBlobGas,
+        Self::ParentBeaconBlockRoot,
+        Self::RequestsHash,
+    ];
+
+    /// Returns the JSON-RPC name of the field.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::BaseFeePerGas => "baseFeePerGas",
+            Self::WithdrawalsRoot => "withdrawalsRoot",
+            Self::BlobGasUsed => "blobGasUsed",
+            Self::ExcessBlobGas => "excessBlobGas",
+            Self::ParentBeaconBlockRoot => "parentBeaconBlockRoot",
+            Self::RequestsHash => "requestsHash",
+        }
+    }
+
+    /// Returns the hardfork introducing the field.
+    pub const fn introduced_by(&self) -> EthereumHardfork {
+        match self {
+            Self::BaseFeePerGas => EthereumHardfork::London,
+            Self::WithdrawalsRoot => EthereumHardfork::Shanghai,
+            Self::BlobGasUsed | Self::ExcessBlobGas | Self::ParentBeaconBlockRoot => {
+                EthereumHardfork::Cancun
+            }
+            Self::RequestsHash => EthereumHardfork::Prague,
+        }
+    }
+}
+
+impl fmt::Display for HeaderField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl EthereumHardfork {
+    /// Returns the header fields introduced by the hardfork, see [`HeaderField::introduced_by`].
+    pub const fn header_fields(&self) -> &'static [HeaderField] {
+        match self {
+            Self::London => &[HeaderField::BaseFeePerGas],
+            Self::Shanghai => &[HeaderField::WithdrawalsRoot],
+            Self::Cancun => &[
+                HeaderField::BlobGasUsed,
+                HeaderField::ExcessBlobGas,
+                HeaderField::ParentBeaconBlockRoot,
+            ],
+            Self::Prague => &[HeaderField::RequestsHash],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlockHead, EthereumChainHardforks, EthereumHardforks, ethereum::*};
+
+    #[test]
+    fn header_fields() {
+        for field in HeaderField::VARIANTS {
+            assert!(field.introduced_by().header_fields().contains(field));
+        }
+
+        let forks = EthereumChainHardforks::mainnet();
+        assert_eq!(forks.header_fields_at(&BlockHead::new(MAINNET_BERLIN_BLOCK, 0)).len(), 0);
+        assert_eq!(
+            forks.header_fields_at(&BlockHead::new(MAINNET_LONDON_BLOCK, 0)),
+            [HeaderField::BaseFeePerGas]
+        );
+        assert_eq!(
+            forks.header_fields_at(&BlockHead::new(MAINNET_CANCUN_BLOCK, MAINNET_CANCUN_TIMESTAMP)),
+            &HeaderField::VARIANTS[..5]
+        );
+        assert!(!forks.is_header_field_required_at(
+            HeaderField::RequestsHash,
+            &BlockHead::new(MAINNET_CANCUN_BLOCK, MAINNET_CANCUN_TIMESTAMP)
+        ));
+        assert!(forks.is_header_field_required_at(
+            HeaderField::RequestsHash,
+            &BlockHead::new(MAINNET_PRAGUE_BLOCK, MAINNET_PRAGUE_TIMESTAMP)
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+//! Rollup fork activation by L1 origin.
+
+use crate::{BlockHead, ForkCondition};
+use alloy_primitives::BlockNumber;
+
+/// The condition at which a rollup fork is activated, in terms of the L1 origin of L2 blocks.
+///
+/// Rollup forks are scheduled by L2 [`ForkCondition`]s, but some rollup rules, e.g. derivation
+/// rule changes, are keyed off the L1 block the L2 chain is derived from instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum L1OriginCondition {
+    /// The fork is activated once the L1 origin reaches a certain block.
+    Block(BlockNumber),
+    /// The fork is activated once the L1 origin reaches a certain timestamp.
+    Timestamp(u64),
+    /// The fork is never activated.
+    #[default]
+    Never,
+}
+
+impl L1OriginCondition {
+    /// Returns the L1 origin condition comparing the L1 origin timestamp with the activation
+    /// timestamp of the given L2 condition, if it is timestamp based, and
+    /// [`L1OriginCondition::Never`] otherwise.
+    pub const fn from_l2_condition(condition: ForkCondition) -> Self {
+        match condition {
+            ForkCondition::Timestamp(timestamp) => Self::Timestamp(timestamp),
+            _ => Self::Never,
+        }
+    }
+
+    /// Checks whether the condition is satisfied at the given L1 origin.
+    pub const fn active_at(&self, origin: &BlockHead) -> bool {
+        match self {
+            Self::Block(block) => origin.number >= *block,
+            Self::Timestamp(timestamp) => origin.timestamp >= *timestamp,
+            Self::Never => false,
+        }
+    }
+
+    /// Checks if `origin` is the first L1 origin satisfying the condition, i.e. the condition is
+    /// satisfied at `origin` but not at the previous L1 origin.
+    pub const fn transitions_at(&self, origin: &BlockHead, previous_origin: &BlockHead) -> bool {
+        self.active_at(origin) && !self.active_at(previous_origin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn l1_origin_condition() {
+        let origin = BlockHead::new(100, 1_200);
+        let previous = BlockHead::new(99, 1_188);
+
+        for condition in [L1OriginCondition::Block(100), L1OriginCondition::Timestamp(1_190)] {
+            assert!(condition.active_at(&origin));
+            assert!(!condition.active_at(&previous));
+            assert!(condition.transitions_at(&origin, &previous));
+            assert!(!condition.transitions_at(&origin, &origin));
+        }
+        assert!(!L1OriginCondition::Never.active_at(&origin));
+
+        assert_eq!(
+            L1OriginCondition::from_l2_condition(ForkCondition::Timestamp(1_190)),
+            L1OriginCondition::Timestamp(1_190)
+        );
+        assert_eq!(
+            L1OriginCondition::from_l2_condition(ForkCondition::Block(100)),
+            L1OriginCondition::Never
+        );
+    }
+}
@@ -12,12 +12,45 @@ extern crate alloc;
 /// Re-exported EIP-2124 forkid types.
 pub use alloy_eip2124::*;
 
+mod beacon;
+pub use beacon::*;
+
+mod blob;
+pub use blob::*;
+
+mod consensus;
+pub use consensus::*;
+
+mod diff;
+pub use diff::*;
+
+mod engine;
+pub use engine::*;
+
 mod forkcondition;
 pub use forkcondition::*;
 
+#[cfg(feature = "serde")]
+mod genesis;
+#[cfg(feature = "serde")]
+pub use genesis::*;
+
 mod hardfork;
 pub use hardfork::*;
 
+mod header;
+pub use header::*;
+
+mod l1origin;
+pub use l1origin::*;
+
+mod opcode;
+
+mod precompile;
+pub use precompile::*;
+
+mod schedule;
+
 /// Error types for the hardforks crate.
 pub mod error;
 pub use error::*;
@@ -30,5 +63,6 @@ pub use arbitrum::{mainnet as arbitrum_mainnet, sepolia as arbitrum_sepolia};
 // Not public API.
 #[doc(hidden)]
 pub mod __private {
+    pub use crate::schedule::out_of_order_violations;
     pub use alloc::{format, string::String};
 }
@@ -0,0 +1,121 @@
+//! EVM opcodes introduced by hardforks.
+
+use crate::EthereumHardfork;
+
+impl EthereumHardfork {
+    /// Returns the hardfork introducing the given opcode, or `None` if the opcode is undefined.
+    ///
+    /// No hardfork has removed an opcode so far, so an opcode is valid from the hardfork
+    /// introducing it onwards. The `INVALID` (`0xfe`) designated invalid instruction is considered
+    /// defined since [`EthereumHardfork::Frontier`].
+    pub const fn from_opcode(opcode: u8) -> Option<Self> {
+        Some(match opcode {
+            0x00..=0x0b
+            | 0x10..=0x1a
+            | 0x20
+            | 0x30..=0x3c
+            | 0x40..=0x45
+            | 0x50..=0x5b
+            | 0x60..=0xa4
+            | 0xf0..=0xf3
+            | 0xfe
+            | 0xff => Self::Frontier,
+            // DELEGATECALL
+            0xf4 => Self::Homestead,
+            // RETURNDATASIZE, RETURNDATACOPY, STATICCALL, REVERT
+            0x3d | 0x3e | 0xfa | 0xfd => Self::Byzantium,
+            // SHL, SHR, SAR, EXTCODEHASH, CREATE2
+            0x1b..=0x1d | 0x3f | 0xf5 => Self::Constantinople,
+            // CHAINID, SELFBALANCE
+            0x46 | 0x47 => Self::Istanbul,
+            // BASEFEE
+            0x48 => Self::London,
+            // PUSH0
+            0x5f => Self::Shanghai,
+            // BLOBHASH, BLOBBASEFEE, TLOAD, TSTORE, MCOPY
+            0x49 | 0x4a | 0x5c..=0x5e => Self::Cancun,
+            // CLZ
+            0x1e => Self::Osaka,
+            _ => return None,
+        })
+    }
+
+    /// Returns an iterator over the opcodes introduced by the hardfork, see
+    /// [`EthereumHardfork::from_opcode`].
+    pub fn opcodes(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=u8::MAX).filter(|opcode| Self::from_opcode(*opcode).is_some_and(|fork| fork == *self))
+    }
+
+    /// Returns the opcodes whose semantics are changed by the hardfork, e.g. `DIFFICULTY`
+    /// (`0x44`) returning `PREVRANDAO` after [`EthereumHardfork::Paris`], or `SELFDESTRUCT`
+    /// (`0xff`) only deleting accounts created in the same transaction after
+    /// [`EthereumHardfork::Cancun`].
+    ///
+    /// Gas cost changes are not included.
+    pub const fn changed_opcodes(&self) -> &'static [u8] {
+        match self {
+            Self::Paris => &[0x44],
+            Self::Cancun => &[0xff],
+            _ => &[],
+        }
+    }
+
+    /// Returns `true` if the given opcode is defined once this hardfork, and all the hardforks
+    /// preceding it, are active.
+    ///
+    /// This is handy for chains only described by their latest hardfork, see
+    /// [`EthereumHardfork::from_chain_and_timestamp`].
+    pub const fn is_opcode_valid(&self, opcode: u8) -> bool {
+        match Self::from_opcode(opcode) {
+            Some(fork) => fork as usize <= *self as usize,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BlockHead, EthereumChainHardforks, EthereumHardforks, arbitrum::mainnet::*, ethereum::*,
+    };
+    use alloc::vec::Vec;
+    use alloy_chains::Chain;
+
+    #[test]
+    fn hardfork_opcodes() {
+        assert_eq!(EthereumHardfork::Frontier.opcodes().count(), 130);
+        assert_eq!(EthereumHardfork::Shanghai.opcodes().collect::<Vec<_>>(), [0x5f]);
+        assert_eq!(
+            EthereumHardfork::Cancun.opcodes().collect::<Vec<_>>(),
+            [0x49, 0x4a, 0x5c, 0x5d, 0x5e]
+        );
+        assert_eq!(EthereumHardfork::Prague.opcodes().count(), 0);
+        assert_eq!(EthereumHardfork::from_opcode(0x0c), None);
+        assert_eq!(EthereumHardfork::from_opcode(0xef), None);
+
+        assert!(EthereumHardfork::Petersburg.is_opcode_valid(0xf5));
+        assert!(!EthereumHardfork::Byzantium.is_opcode_valid(0xf5));
+        assert!(!EthereumHardfork::Prague.is_opcode_valid(0x1e));
+    }
+
+    #[test]
+    fn is_opcode_valid_at() {
+        let forks = EthereumChainHardforks::mainnet();
+        assert!(!forks.is_opcode_valid_at(0x5f, &BlockHead::new(MAINNET_SHANGHAI_BLOCK, 0)));
+        assert!(forks.is_opcode_valid_at(
+            0x5f,
+            &BlockHead::new(MAINNET_SHANGHAI_BLOCK, MAINNET_SHANGHAI_TIMESTAMP)
+        ));
+        assert!(forks.is_opcode_valid_at(0x00, &BlockHead::new(0, 0)));
+        assert!(!forks.is_opcode_valid_at(0x0c, &BlockHead::new(u64::MAX, u64::MAX)));
+
+        // Arbitrum schedules are described by their latest hardfork.
+        let is_valid = |opcode, timestamp| {
+            EthereumHardfork::from_chain_and_timestamp(Chain::arbitrum_mainnet(), timestamp)
+                .is_some_and(|fork| fork.is_opcode_valid(opcode))
+        };
+        assert!(!is_valid(0x5c, ARBITRUM_ONE_CANCUN_TIMESTAMP - 1));
+        assert!(is_valid(0x5c, ARBITRUM_ONE_CANCUN_TIMESTAMP));
+    }
+}
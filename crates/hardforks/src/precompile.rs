@@ -0,0 +1,203 @@
+//! Precompiled contracts introduced by hardforks.
+
+use crate::EthereumHardfork;
+use alloy_primitives::{Address, address};
+use core::fmt;
+
+/// A precompiled contract.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Precompile {
+    /// `ECRECOVER`, at `0x01`.
+    EcRecover,
+    /// `SHA256`, at `0x02`.
+    Sha256,
+    /// `RIPEMD160`, at `0x03`.
+    Ripemd160,
+    /// `IDENTITY`, at `0x04`.
+    Identity,
+    /// `MODEXP`, at `0x05`, introduced by [EIP-198](https://eips.ethereum.org/EIPS/eip-198).
+    ModExp,
+    /// `BN254_ADD`, at `0x06`, introduced by [EIP-196](https://eips.ethereum.org/EIPS/eip-196).
+    Bn254Add,
+    /// `BN254_MUL`, at `0x07`, introduced by [EIP-196](https://eips.ethereum.org/EIPS/eip-196).
+    Bn254Mul,
+    /// `BN254_PAIRING`, at `0x08`, introduced by [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+    Bn254Pairing,
+    /// `BLAKE2F`, at `0x09`, introduced by [EIP-152](https://eips.ethereum.org/EIPS/eip-152).
+    Blake2F,
+    /// `POINT_EVALUATION`, at `0x0a`, introduced by [EIP-4844](https://eips.ethereum.org/EIPS/eip-4844).
+    PointEvaluation,
+    /// `BLS12_G1ADD`, at `0x0b`, introduced by [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+    Bls12G1Add,
+    /// `BLS12_G1MSM`, at `0x0c`, introduced by [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+    Bls12G1Msm,
+    /// `BLS12_G2ADD`, at `0x0d`, introduced by [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+    Bls12G2Add,
+    /// `BLS12_G2MSM`, at `0x0e`, introduced by [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+    Bls12G2Msm,
+    /// `BLS12_PAIRING_CHECK`, at `0x0f`, introduced by [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+    Bls12PairingCheck,
+    /// `BLS12_MAP_FP_TO_G1`, at `0x10`, introduced by [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+    Bls12MapFpToG1,
+    /// `BLS12_MAP_FP2_TO_G2`, at `0x11`, introduced by [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+    Bls12MapFp2ToG2,
+    /// `P256VERIFY`, at `0x100`, introduced by [EIP-7951](https://eips.ethereum.org/EIPS/eip-7951).
+    P256Verify,
+}
+
+impl Precompile {
+    /// All precompiles, ordered by address.
+    pub const VARIANTS: &'static [Self] = &[
+        Self::EcRecover,
+        Self::Sha256,
+        Self::Ripemd160,
+        Self::Identity,
+        Self::ModExp,
+        Self::Bn254Add,
+        Self::Bn254Mul,
+        Self::Bn254Pairing,
+        Self::Blake2F,
+        Self::PointEvaluation,
+        Self::Bls12G1Add,
+        Self::Bls12G1Msm,
+        Self::Bls12G2Add,
+        Self::Bls12G2Msm,
+        Self::Bls12PairingCheck,
+        Self::Bls12MapFpToG1,
+        Self::Bls12MapFp2ToG2,
+        Self::P256Verify,
+    ];
+
+    /// Returns the address of the precompile.
+    pub const fn address(&self) -> Address {
+        match self {
+            Self::P256Verify => address!("0x0000000000000000000000000000000000000100"),
+            // The remaining precompiles are numbered consecutively from `0x01`.
+            _ => Address::with_last_byte(*self as u8 + 1),
+        }
+    }
+
+    /// Returns the precompile at the given address, if any.
+    pub fn from_address(address: Address) -> Option<Self> {
+        Self::VARIANTS.iter().copied().find(|precompile| precompile.address() == address)
+    }
+
+    /// Returns the Ethereum hardfork introducing the precompile.
+    pub const fn introduced_by(&self) -> EthereumHardfork {
+        match self {
+            Self::EcRecover | Self::Sha256 | Self::Ripemd160 | Self::Identity => {
+                EthereumHardfork::Frontier
+            }
+            Self::ModExp | Self::Bn254Add | Self::Bn254Mul | Self::Bn254Pairing => {
+                EthereumHardfork::Byzantium
+            }
+            Self::Blake2F => EthereumHardfork::Istanbul,
+            Self::PointEvaluation => EthereumHardfork::Cancun,
+            Self::Bls12G1Add
+            | Self::Bls12G1Msm
+            | Self::Bls12G2Add
+            | Self::Bls12G2Msm
+            | Self::Bls12PairingCheck
+            | Self::Bls12MapFpToG1
+            | Self::Bls12MapFp2ToG2 => EthereumHardfork::Prague,
+            Self::P256Verify => EthereumHardfork::Osaka,
+        }
+    }
+}
+
+impl fmt::Display for Precompile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl EthereumHardfork {
+    /// Returns the precompiles introduced by the hardfork, see [`Precompile::introduced_by`].
+    pub const fn precompiles(&self) -> &'static [Precompile] {
+        match self {
+            Self::Frontier => &[
+                Precompile::EcRecover,
+                Precompile::Sha256,
+                Precompile::Ripemd160,
+                Precompile::Identity,
+            ],
+            Self::Byzantium => &[
+                Precompile::ModExp,
+                Precompile::Bn254Add,
+                Precompile::Bn254Mul,
+                Precompile::Bn254Pairing,
+            ],
+            Self::Istanbul => &[Precompile::Blake2F],
+            Self::Cancun => &[Precompile::PointEvaluation],
+            Self::Prague => &[
+                Precompile::Bls12G1Add,
+                Precompile::Bls12G1Msm,
+                Precompile::Bls12G2Add,
+                Precompile::Bls12G2Msm,
+                Precompile::Bls12PairingCheck,
+                Precompile::Bls12MapFpToG1,
+                Precompile::Bls12MapFp2ToG2,
+            ],
+            Self::Osaka => &[Precompile::P256Verify],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlockHead, EthereumChainHardforks, EthereumHardforks, ethereum::*};
+
+    #[test]
+    fn precompile_addresses() {
+        assert_eq!(Precompile::EcRecover.address(), Address::with_last_byte(0x01));
+        assert_eq!(Precompile::Blake2F.address(), Address::with_last_byte(0x09));
+        assert_eq!(Precompile::PointEvaluation.address(), Address::with_last_byte(0x0a));
+        assert_eq!(Precompile::Bls12MapFp2ToG2.address(), Address::with_last_byte(0x11));
+        assert!(Precompile::VARIANTS.is_sorted_by_key(|precompile| precompile.address()));
+        for precompile in Precompile::VARIANTS {
+            assert_eq!(Precompile::from_address(precompile.address()), Some(*precompile));
+        }
+        assert_eq!(Precompile::from_address(Address::with_last_byte(0x12)), None);
+
+        for precompile in Precompile::VARIANTS {
+            assert!(precompile.introduced_by().precompiles().contains(precompile));
+        }
+        assert_eq!(
+            EthereumHardfork::VARIANTS.iter().map(|fork| fork.precompiles().len()).sum::<usize>(),
+            Precompile::VARIANTS.len()
+        );
+    }
+
+    #[test]
+    fn precompiles_at() {
+        let forks = EthereumChainHardforks::mainnet();
+        assert_eq!(forks.precompiles_at(&BlockHead::new(0, 0)).len(), 4);
+        assert_eq!(forks.precompiles_at(&BlockHead::new(MAINNET_ISTANBUL_BLOCK, 0)).len(), 9);
+        assert_eq!(
+            forks
+                .precompiles_at(&BlockHead::new(MAINNET_CANCUN_BLOCK, MAINNET_CANCUN_TIMESTAMP))
+                .last(),
+            Some(&Precompile::PointEvaluation)
+        );
+        assert_eq!(
+            forks
+                .precompiles_at(&BlockHead::new(MAINNET_PRAGUE_BLOCK, MAINNET_PRAGUE_TIMESTAMP))
+                .len(),
+            Precompile::VARIANTS.len() - 1
+        );
+
+        let p256 = address!("0x0000000000000000000000000000000000000100");
+        assert!(
+            !forks.is_precompile_active_at(p256, &BlockHead::new(MAINNET_PRAGUE_BLOCK, u64::MAX))
+        );
+        let forks = EthereumChainHardforks::sepolia();
+        assert!(forks.is_precompile_active_at(p256, &BlockHead::new(0, SEPOLIA_OSAKA_TIMESTAMP)));
+        assert!(
+            !forks.is_precompile_active_at(p256, &BlockHead::new(0, SEPOLIA_OSAKA_TIMESTAMP - 1))
+        );
+        assert!(!forks.is_precompile_active_at(Address::ZERO, &BlockHead::new(0, u64::MAX)));
+    }
+}
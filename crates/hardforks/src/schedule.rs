@@ -0,0 +1,73 @@
+//! Consistency checks of hardfork schedules.
+
+use crate::{ForkCondition, ForkScheduleViolation};
+use alloc::vec::Vec;
+
+/// Returns the ordering violations of the given hardfork activations, which are expected in
+/// canonical order.
+///
+/// Block based activations (including the activation block of a [`ForkCondition::TTD`]) and
+/// timestamp based ones are compared among themselves, and no block based activation may follow a
+/// timestamp based one. [`ForkCondition::Tentative`] and [`ForkCondition::Never`] are skipped.
+pub fn out_of_order_violations<F: Copy>(
+    forks: impl IntoIterator<Item = (F, ForkCondition)>,
+) -> Vec<ForkScheduleViolation<F>> {
+    let mut violations = Vec::new();
+    let mut latest_block: Option<(F, ForkCondition, u64)> = None;
+    let mut latest_timestamp: Option<(F, ForkCondition, u64)> = None;
+
+    for (fork, condition) in forks {
+        let (value, is_block) = match condition {
+            ForkCondition::Block(block)
+            | ForkCondition::TTD { activation_block_number: block, .. } => (block, true),
+            ForkCondition::Timestamp(timestamp) => (timestamp, false),
+            ForkCondition::Tentative(_) | ForkCondition::Never => continue,
+        };
+
+        let later = |latest: Option<(_, _, u64)>| latest.filter(|(_, _, latest)| value < *latest);
+        let previous = if is_block {
+            latest_timestamp.or_else(|| later(latest_block))
+        } else {
+            later(latest_timestamp)
+        };
+        match previous {
+            Some((previous, previous_condition, _)) => {
+                violations.push(ForkScheduleViolation::OutOfOrder {
+                    fork,
+                    condition,
+                    previous,
+                    previous_condition,
+                })
+            }
+            None if is_block => latest_block = Some((fork, condition, value)),
+            None => latest_timestamp = Some((fork, condition, value)),
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EthereumHardfork;
+
+    #[test]
+    fn timestamp_forks_precede_block_forks() {
+        let violations = out_of_order_violations([
+            (EthereumHardfork::London, ForkCondition::Block(10)),
+            (EthereumHardfork::Shanghai, ForkCondition::Timestamp(0)),
+            (EthereumHardfork::Cancun, ForkCondition::Never),
+            (EthereumHardfork::Prague, ForkCondition::Block(20)),
+        ]);
+        assert_eq!(
+            violations,
+            [ForkScheduleViolation::OutOfOrder {
+                fork: EthereumHardfork::Prague,
+                condition: ForkCondition::Block(20),
+                previous: EthereumHardfork::Shanghai,
+                previous_condition: ForkCondition::Timestamp(0),
+            }]
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Hardfork schedules of op-geth-style `genesis.json` files.
+
+use crate::{OpHardfork, OpHardforks};
+use alloy_hardforks::{GenesisConfigError, GenesisForkConfig};
+
+/// The hardfork activations of the `config` section of an op-geth-style `genesis.json`.
+///
+/// Extends the Ethereum activations of [`GenesisForkConfig`] with the [`OpHardfork`] ones. The
+/// OP keys are not reported by [`GenesisForkConfig::unknown_fork_keys`] of the flattened config.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpGenesisForkConfig {
+    /// The Ethereum hardfork activations.
+    #[serde(flatten)]
+    pub ethereum: GenesisForkConfig,
+    /// [`OpHardfork::Bedrock`] activation block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bedrock_block: Option<u64>,
+    /// [`OpHardfork::Regolith`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regolith_time: Option<u64>,
+    /// [`OpHardfork::Canyon`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canyon_time: Option<u64>,
+    /// [`OpHardfork::Ecotone`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ecotone_time: Option<u64>,
+    /// [`OpHardfork::Fjord`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fjord_time: Option<u64>,
+    /// [`OpHardfork::Granite`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granite_time: Option<u64>,
+    /// [`OpHardfork::Holocene`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holocene_time: Option<u64>,
+    /// [`OpHardfork::Isthmus`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isthmus_time: Option<u64>,
+    /// [`OpHardfork::Jovian`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jovian_time: Option<u64>,
+    /// [`OpHardfork::Interop`] activation timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interop_time: Option<u64>,
+}
+
+impl OpGenesisForkConfig {
+    /// Creates the genesis `config` activations of the given hardfork schedule.
+    ///
+    /// See [`GenesisForkConfig::from_hardforks`].
+    pub fn from_hardforks(hardforks: &impl OpHardforks) -> Result<Self, GenesisConfigError> {
+        use OpHardfork::*;
+
+        let time = |fork, key| {
+            GenesisForkConfig::timestamp_activation(key, hardforks.op_fork_activation(fork))
+        };
+
+        Ok(Self {
+            ethereum: GenesisForkConfig::from_hardforks(hardforks)?,
+            bedrock_block: GenesisForkConfig::block_activation(
+                "bedrockBlock",
+                hardforks.op_fork_activation(Bedrock),
+            )?,
+            regolith_time: time(Regolith, "regolithTime")?,
+            canyon_time: time(Canyon, "canyonTime")?,
+            ecotone_time: time(Ecotone, "ecotoneTime")?,
+            fjord_time: time(Fjord, "fjordTime")?,
+            granite_time: time(Granite, "graniteTime")?,
+            holocene_time: time(Holocene, "holoceneTime")?,
+            isthmus_time: time(Isthmus, "isthmusTime")?,
+            jovian_time: time(Jovian, "jovianTime")?,
+            interop_time: time(Interop, "interopTime")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        OP_MAINNET_BEDROCK_BLOCK, OP_MAINNET_CANYON_TIMESTAMP, OP_MAINNET_JOVIAN_TIMESTAMP,
+        OpChainHardforks,
+    };
+    use alloc::format;
+
+    #[test]
+    fn op_mainnet_genesis_config() {
+        let config = OpGenesisForkConfig::from_hardforks(&OpChainHardforks::op_mainnet()).unwrap();
+        assert_eq!(config.bedrock_block, Some(OP_MAINNET_BEDROCK_BLOCK));
+        assert_eq!(config.ethereum.london_block, Some(OP_MAINNET_BEDROCK_BLOCK));
+        assert_eq!(config.ethereum.merge_netsplit_block, Some(OP_MAINNET_BEDROCK_BLOCK));
+        assert_eq!(config.ethereum.shanghai_time, Some(OP_MAINNET_CANYON_TIMESTAMP));
+        assert_eq!(config.interop_time, None);
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(&format!(r#""bedrockBlock":{OP_MAINNET_BEDROCK_BLOCK}"#)));
+        assert!(json.contains(&format!(r#""canyonTime":{OP_MAINNET_CANYON_TIMESTAMP}"#)));
+        assert!(json.contains(&format!(r#""jovianTime":{OP_MAINNET_JOVIAN_TIMESTAMP}"#)));
+        assert!(json.contains(r#""terminalTotalDifficulty":0"#));
+        assert!(!json.contains("interopTime"));
+        assert!(!json.contains("daoForkBlock"));
+
+        let parsed: OpGenesisForkConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, config);
+        assert_eq!(parsed.ethereum.unknown_fork_keys().count(), 0);
+
+        // The OP keys are unknown to the Ethereum config.
+        let ethereum: GenesisForkConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(ethereum.unknown_fork_keys().count(), 9);
+    }
+}
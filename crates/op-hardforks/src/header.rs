@@ -0,0 +1,99 @@
+//! OP specific block header field semantics introduced by hardforks.
+//!
+//! Which header fields are present follows the included Ethereum hardforks, see
+//! [`EthereumHardforks::header_fields_at`](crate::EthereumHardforks::header_fields_at).
+
+use crate::OpHardfork;
+
+/// An OP specific rule on the contents of a block header field.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum OpHeaderRule {
+    /// `extraData` encodes the EIP-1559 denominator and elasticity of the block.
+    Eip1559ParamsInExtraData,
+    /// `withdrawalsRoot` is the storage root of the `L2ToL1MessagePasser` predeploy.
+    WithdrawalsRootIsMessagePasserStorageRoot,
+    /// `extraData` additionally encodes the minimum base fee of the block.
+    MinBaseFeeInExtraData,
+    /// `blobGasUsed` is the data availability footprint of the block.
+    BlobGasUsedIsDaFootprint,
+}
+
+impl OpHeaderRule {
+    /// All header rules.
+    pub const VARIANTS: &'static [Self] = &[
+        Self::Eip1559ParamsInExtraData,
+        Self::WithdrawalsRootIsMessagePasserStorageRoot,
+        Self::MinBaseFeeInExtraData,
+        Self::BlobGasUsedIsDaFootprint,
+    ];
+
+    /// Returns the hardfork introducing the rule.
+    pub const fn introduced_by(&self) -> OpHardfork {
+        match self {
+            Self::Eip1559ParamsInExtraData => OpHardfork::Holocene,
+            Self::WithdrawalsRootIsMessagePasserStorageRoot => OpHardfork::Isthmus,
+            Self::MinBaseFeeInExtraData | Self::BlobGasUsedIsDaFootprint => OpHardfork::Jovian,
+        }
+    }
+}
+
+impl OpHardfork {
+    /// Returns the header rules introduced by the hardfork, see [`OpHeaderRule::introduced_by`].
+    pub const fn header_rules(&self) -> &'static [OpHeaderRule] {
+        match self {
+            Self::Holocene => &[OpHeaderRule::Eip1559ParamsInExtraData],
+            Self::Isthmus => &[OpHeaderRule::WithdrawalsRootIsMessagePasserStorageRoot],
+            Self::Jovian => {
+                &[OpHeaderRule::MinBaseFeeInExtraData, OpHeaderRule::BlobGasUsedIsDaFootprint]
+            }
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        OP_MAINNET_ECOTONE_TIMESTAMP, OP_MAINNET_ISTHMUS_TIMESTAMP, OpChainHardforks, OpHardforks,
+    };
+    use alloy_hardforks::{BlockHead, EthereumHardforks, HeaderField};
+
+    #[test]
+    fn op_header_rules() {
+        for rule in OpHeaderRule::VARIANTS {
+            assert!(rule.introduced_by().header_rules().contains(rule));
+        }
+
+        let forks = OpChainHardforks::op_mainnet();
+        let block = forks.op_fork_activation(OpHardfork::Bedrock).block_number().unwrap();
+        assert_eq!(
+            forks.op_header_rules_at(&BlockHead::new(block, OP_MAINNET_ECOTONE_TIMESTAMP)).len(),
+            0
+        );
+        assert_eq!(
+            forks.op_header_rules_at(&BlockHead::new(block, OP_MAINNET_ISTHMUS_TIMESTAMP)),
+            &OpHeaderRule::VARIANTS[..2]
+        );
+        assert_eq!(
+            forks.op_header_rules_at(&BlockHead::new(block, OP_MAINNET_ISTHMUS_TIMESTAMP - 1)),
+            &OpHeaderRule::VARIANTS[..1]
+        );
+
+        // Header fields follow the implied Ethereum hardforks.
+        assert!(forks.is_header_field_required_at(
+            HeaderField::ParentBeaconBlockRoot,
+            &BlockHead::new(block, OP_MAINNET_ECOTONE_TIMESTAMP)
+        ));
+        assert!(forks.is_header_field_required_at(
+            HeaderField::RequestsHash,
+            &BlockHead::new(block, OP_MAINNET_ISTHMUS_TIMESTAMP)
+        ));
+        assert!(!forks.is_header_field_required_at(
+            HeaderField::RequestsHash,
+            &BlockHead::new(block, OP_MAINNET_ISTHMUS_TIMESTAMP - 1)
+        ));
+    }
+}
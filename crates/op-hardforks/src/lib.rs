@@ -10,9 +10,12 @@
 extern crate alloc;
 use alloc::vec::Vec;
 use alloy_chains::{Chain, NamedChain};
-use alloy_hardforks::{EthereumHardfork, hardfork};
-pub use alloy_hardforks::{EthereumHardforks, ForkCondition};
-use alloy_primitives::U256;
+use alloy_hardforks::{
+    BlockHead, EthereumHardfork, ForkChange, ForkFilterKey, ForkScheduleError,
+    ForkScheduleViolation, NextFork, Precompile, hardfork,
+};
+pub use alloy_hardforks::{EthereumHardforks, ForkCondition, L1OriginCondition};
+use alloy_primitives::{Address, U256};
 use core::ops::Index;
 
 pub mod optimism;
@@ -21,6 +24,16 @@ pub use optimism::{mainnet as op_mainnet, mainnet::*, sepolia as op_sepolia, sep
 pub mod base;
 pub use base::{mainnet as base_mainnet, mainnet::*, sepolia as base_sepolia, sepolia::*};
 
+#[cfg(feature = "serde")]
+mod genesis;
+#[cfg(feature = "serde")]
+pub use genesis::*;
+
+mod header;
+pub use header::*;
+
+mod precompile;
+
 hardfork!(
     /// The name of an optimism hardfork.
     ///
@@ -192,6 +205,23 @@ pub trait OpHardforks: EthereumHardforks {
     /// [`ForkCondition::Never`].
     fn op_fork_activation(&self, fork: OpHardfork) -> ForkCondition;
 
+    /// Convenience method to check if an [`OpHardfork`] is active at a given head, see
+    /// [`ForkCondition::active_at`].
+    fn is_op_fork_active_at(&self, fork: OpHardfork, head: &BlockHead) -> bool {
+        self.op_fork_activation(fork).active_at(head)
+    }
+
+    /// Convenience method to check if an [`OpHardfork`] activates at a given head, see
+    /// [`ForkCondition::transitions_at`].
+    fn op_fork_transitions_at(
+        &self,
+        fork: OpHardfork,
+        head: &BlockHead,
+        parent: &BlockHead,
+    ) -> bool {
+        self.op_fork_activation(fork).transitions_at(head, parent)
+    }
+
     /// Convenience method to check if [`OpHardfork::Bedrock`] is active at a given block
     /// number.
     fn is_bedrock_active_at_block(&self, block_number: u64) -> bool {
@@ -247,6 +277,111 @@ pub trait OpHardforks: EthereumHardforks {
     fn is_interop_active_at_timestamp(&self, timestamp: u64) -> bool {
         self.op_fork_activation(OpHardfork::Interop).active_at_timestamp(timestamp)
     }
+
+    /// Returns the [`OpHeaderRule`]s applying to the header of the given block.
+    fn op_header_rules_at(&self, head: &BlockHead) -> Vec<OpHeaderRule> {
+        OpHeaderRule::VARIANTS
+            .iter()
+            .copied()
+            .filter(|rule| self.op_fork_activation(rule.introduced_by()).active_at(head))
+            .collect()
+    }
+
+    /// Retrieves the [`L1OriginCondition`] activating the L1 origin keyed rules of an
+    /// [`OpHardfork`], e.g. derivation rule changes.
+    ///
+    /// Defaults to comparing the L1 origin timestamp with the L2 activation timestamp of the fork,
+    /// as the derivation pipeline does, see [`L1OriginCondition::from_l2_condition`].
+    fn op_fork_l1_origin_activation(&self, fork: OpHardfork) -> L1OriginCondition {
+        L1OriginCondition::from_l2_condition(self.op_fork_activation(fork))
+    }
+
+    /// Convenience method to check if the L1 origin keyed rules of an [`OpHardfork`] are active at
+    /// a given L1 origin.
+    fn is_op_fork_active_at_l1_origin(&self, fork: OpHardfork, origin: &BlockHead) -> bool {
+        self.op_fork_l1_origin_activation(fork).active_at(origin)
+    }
+
+    /// Convenience method to check if the L1 origin keyed rules of an [`OpHardfork`] activate at a
+    /// given L1 origin, see [`L1OriginCondition::transitions_at`].
+    fn op_fork_transitions_at_l1_origin(
+        &self,
+        fork: OpHardfork,
+        origin: &BlockHead,
+        previous_origin: &BlockHead,
+    ) -> bool {
+        self.op_fork_l1_origin_activation(fork).transitions_at(origin, previous_origin)
+    }
+
+    /// Retrieves the [`ForkCondition`] activating the given [`Precompile`] on the OP chain, i.e.
+    /// the activation of the [`OpHardfork`] introducing it, see [`OpHardfork::precompiles`], or
+    /// else of the [`EthereumHardfork`] introducing it.
+    ///
+    /// The precompile queries of [`EthereumHardforks`] follow the Ethereum schedule, use
+    /// [`OpHardforks::op_precompiles_at`] and [`OpHardforks::is_op_precompile_active_at`] on OP
+    /// chains, e.g. for `P256VERIFY` activating with [`Fjord`](OpHardfork::Fjord).
+    fn op_precompile_activation(&self, precompile: Precompile) -> ForkCondition {
+        OpHardfork::VARIANTS
+            .iter()
+            .find(|fork| fork.precompiles().contains(&precompile))
+            .map(|fork| self.op_fork_activation(*fork))
+            .unwrap_or_else(|| self.ethereum_fork_activation(precompile.introduced_by()))
+    }
+
+    /// Returns the [`Precompile`]s active on the OP chain at the given head, ordered by address,
+    /// see [`OpHardforks::op_precompile_activation`].
+    fn op_precompiles_at(&self, head: &BlockHead) -> Vec<Precompile> {
+        Precompile::VARIANTS
+            .iter()
+            .copied()
+            .filter(|precompile| self.op_precompile_activation(*precompile).active_at(head))
+            .collect()
+    }
+
+    /// Returns `true` if the given address is an active precompile on the OP chain at the given
+    /// head, see [`OpHardforks::op_precompile_activation`].
+    fn is_op_precompile_active_at(&self, address: Address, head: &BlockHead) -> bool {
+        Precompile::from_address(address)
+            .is_some_and(|precompile| self.op_precompile_activation(precompile).active_at(head))
+    }
+}
+
+/// Returns the [`OpHardfork`]s activating before an [`EthereumHardfork`] they include, directly or
+/// through the Ethereum hardforks it builds on, e.g. [`Canyon`](OpHardfork::Canyon) before
+/// [`Shanghai`](EthereumHardfork::Shanghai), or [`Ecotone`](OpHardfork::Ecotone) before
+/// [`Shanghai`](EthereumHardfork::Shanghai) as included by [`Canyon`](OpHardfork::Canyon).
+fn implied_fork_violations(hardforks: &impl OpHardforks) -> Vec<ForkScheduleViolation<OpHardfork>> {
+    const INCLUDED: [(OpHardfork, EthereumHardfork); 4] = [
+        (OpHardfork::Bedrock, EthereumHardfork::London),
+        (OpHardfork::Canyon, EthereumHardfork::Shanghai),
+        (OpHardfork::Ecotone, EthereumHardfork::Cancun),
+        (OpHardfork::Isthmus, EthereumHardfork::Prague),
+    ];
+
+    INCLUDED
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, (fork, _))| INCLUDED[..=idx].iter().map(|(_, implied)| (*fork, *implied)))
+        .filter_map(|(fork, implied)| {
+            let condition = hardforks.op_fork_activation(fork);
+            let implied_condition = hardforks.ethereum_fork_activation(implied);
+            let precedes = match (condition, implied_condition) {
+                (ForkCondition::Tentative(_) | ForkCondition::Never, _) => false,
+                (_, ForkCondition::Tentative(_) | ForkCondition::Never) => true,
+                (ForkCondition::Block(value), ForkCondition::Block(implied))
+                | (ForkCondition::Timestamp(value), ForkCondition::Timestamp(implied)) => {
+                    value < implied
+                }
+                _ => false,
+            };
+            precedes.then_some(ForkScheduleViolation::PrecedesImpliedFork {
+                fork,
+                condition,
+                implied,
+                implied_condition,
+            })
+        })
+        .collect()
 }
 
 /// A type allowing to configure activation [`ForkCondition`]s for a given list of
@@ -264,6 +399,9 @@ pub trait OpHardforks: EthereumHardforks {
 pub struct OpChainHardforks {
     /// Ordered list of OP hardfork activations.
     forks: Vec<(OpHardfork, ForkCondition)>,
+    /// L1 origin activations overriding the default ones, see
+    /// [`OpHardforks::op_fork_l1_origin_activation`].
+    l1_origin_forks: Vec<(OpHardfork, L1OriginCondition)>,
 }
 
 impl OpChainHardforks {
@@ -272,7 +410,83 @@ impl OpChainHardforks {
     pub fn new(forks: impl IntoIterator<Item = (OpHardfork, ForkCondition)>) -> Self {
         let mut forks = forks.into_iter().collect::<Vec<_>>();
         forks.sort();
-        Self { forks }
+        Self { forks, l1_origin_forks: Vec::new() }
+    }
+
+    /// Sets the [`L1OriginCondition`] activating the L1 origin keyed rules of the given fork,
+    /// replacing the default one, see [`OpHardforks::op_fork_l1_origin_activation`].
+    pub fn with_l1_origin_activation(
+        mut self,
+        fork: OpHardfork,
+        condition: L1OriginCondition,
+    ) -> Self {
+        self.l1_origin_forks.retain(|(existing, _)| *existing != fork);
+        self.l1_origin_forks.push((fork, condition));
+        self
+    }
+
+    /// Creates a new [`OpChainHardforks`] with the given list of forks, checking that they form a
+    /// consistent schedule.
+    ///
+    /// Unlike [`Self::new`], returns an error listing every violation: hardforks listed more than
+    /// once, activated by the wrong kind of condition or before a preceding hardfork, hardforks
+    /// listed without all hardforks preceding them, and hardforks activating before the
+    /// [`EthereumHardfork`] they include.
+    pub fn try_new(
+        forks: impl IntoIterator<Item = (OpHardfork, ForkCondition)>,
+    ) -> Result<Self, ForkScheduleError<OpHardfork>> {
+        let hardforks = Self::new(forks);
+        let violations = hardforks.schedule_violations();
+        if violations.is_empty() { Ok(hardforks) } else { Err(ForkScheduleError::new(violations)) }
+    }
+
+    /// Returns the inconsistencies of the schedule, see [`Self::try_new`].
+    pub fn schedule_violations(&self) -> Vec<ForkScheduleViolation<OpHardfork>> {
+        let mut violations = Vec::new();
+        let mut forks = Vec::with_capacity(self.forks.len());
+
+        for (fork, condition) in self.forks.iter().copied() {
+            let previous = forks.last().copied();
+            if previous.is_some_and(|(previous, _)| previous == fork) {
+                let duplicate = ForkScheduleViolation::Duplicate { fork };
+                if violations.last() != Some(&duplicate) {
+                    violations.push(duplicate);
+                }
+                continue;
+            }
+            forks.push((fork, condition));
+
+            let supported = match condition {
+                ForkCondition::Never => true,
+                ForkCondition::Block(_) => fork == OpHardfork::Bedrock,
+                ForkCondition::Timestamp(_) | ForkCondition::Tentative(_) => {
+                    fork != OpHardfork::Bedrock
+                }
+                ForkCondition::TTD { .. } => false,
+            };
+            if !supported {
+                violations.push(ForkScheduleViolation::InvalidCondition { fork, condition });
+            }
+
+            // Forks are looked up by index, so every preceding fork has to be listed.
+            if let Some(required) = fork.idx().checked_sub(1).map(|idx| OpHardfork::VARIANTS[idx]) {
+                let missing = match previous {
+                    Some((previous, previous_condition)) if previous == required => {
+                        (condition != ForkCondition::Never
+                            && previous_condition == ForkCondition::Never)
+                            || (condition.is_confirmed() && previous_condition.is_tentative())
+                    }
+                    _ => true,
+                };
+                if missing {
+                    violations.push(ForkScheduleViolation::MissingFork { fork, required });
+                }
+            }
+        }
+
+        violations.extend(alloy_hardforks::__private::out_of_order_violations(forks));
+        violations.extend(implied_fork_violations(self));
+        violations
     }
 
     /// Creates a new [`OpChainHardforks`] with OP mainnet configuration.
@@ -300,6 +514,18 @@ impl OpChainHardforks {
         Self::new(OpHardfork::devnet())
     }
 
+    /// Compares the schedule to `other`, returning the [`OpHardfork`]s added, removed or moved in
+    /// `other`.
+    pub fn diff(&self, other: &Self) -> Vec<ForkChange<OpHardfork>> {
+        ForkChange::diff(self.forks.iter().copied(), other.forks.iter().copied())
+    }
+
+    /// Returns the scheduled [`OpHardfork`] activating soonest after the given head, with the
+    /// number of blocks or seconds left until its activation, see [`NextFork::find`].
+    pub fn next_fork(&self, head: &BlockHead) -> Option<NextFork<OpHardfork>> {
+        NextFork::find(self.forks.iter().copied(), head)
+    }
+
     /// Returns `true` if this is an OP mainnet instance.
     pub fn is_op_mainnet(&self) -> bool {
         self[OpHardfork::Bedrock] == ForkCondition::Block(OP_MAINNET_BEDROCK_BLOCK)
@@ -324,6 +550,16 @@ impl EthereumHardforks for OpChainHardforks {
             _ => self[fork],
         }
     }
+
+    fn fork_filter_keys(&self) -> Vec<ForkFilterKey> {
+        // OP hardforks are fork points on their own, on top of the Ethereum ones they imply.
+        EthereumHardfork::VARIANTS
+            .iter()
+            .map(|fork| self.ethereum_fork_activation(*fork))
+            .chain(self.forks.iter().map(|(_, condition)| *condition))
+            .filter_map(|condition| condition.fork_filter_key())
+            .collect()
+    }
 }
 
 impl OpHardforks for OpChainHardforks {
@@ -334,6 +570,14 @@ impl OpHardforks for OpChainHardforks {
         }
         self[fork]
     }
+
+    fn op_fork_l1_origin_activation(&self, fork: OpHardfork) -> L1OriginCondition {
+        self.l1_origin_forks
+            .iter()
+            .find(|(existing, _)| *existing == fork)
+            .map(|(_, condition)| *condition)
+            .unwrap_or_else(|| L1OriginCondition::from_l2_condition(self.op_fork_activation(fork)))
+    }
 }
 
 impl Index<OpHardfork> for OpChainHardforks {
@@ -395,10 +639,19 @@ impl Index<EthereumHardfork> for OpChainHardforks {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_hardforks::{ForkCountdown, ForkHash, ForkId, Head};
+    use alloy_primitives::{B256, b256, hex};
     use core::str::FromStr;
 
     extern crate alloc;
 
+    #[test]
+    fn object_safe() {
+        let forks: &dyn OpHardforks = &OpChainHardforks::op_mainnet();
+        assert!(forks.is_bedrock_active_at_block(OP_MAINNET_BEDROCK_BLOCK));
+        assert!(forks.tentative_ethereum_forks().is_empty());
+    }
+
     #[test]
     fn check_op_hardfork_from_str() {
         let hardfork_str = [
@@ -429,6 +682,167 @@ mod tests {
         assert!(OpHardfork::from_str("not a hardfork").is_err());
     }
 
+    #[test]
+    fn schedule_diff() {
+        let changes = OpChainHardforks::base_sepolia().diff(&OpChainHardforks::base_mainnet());
+        assert!(changes.iter().all(|change| matches!(change, ForkChange::Moved { .. })));
+        assert_eq!(
+            changes[0],
+            ForkChange::Moved {
+                fork: OpHardfork::Canyon,
+                old: ForkCondition::Timestamp(BASE_SEPOLIA_CANYON_TIMESTAMP),
+                new: ForkCondition::Timestamp(BASE_MAINNET_CANYON_TIMESTAMP),
+            }
+        );
+
+        let mut forks = OpHardfork::devnet().to_vec();
+        forks.push((OpHardfork::Interop, ForkCondition::Timestamp(1_800_000_000)));
+        assert_eq!(
+            OpChainHardforks::devnet().diff(&OpChainHardforks::new(forks)),
+            [ForkChange::Added {
+                fork: OpHardfork::Interop,
+                condition: ForkCondition::Timestamp(1_800_000_000)
+            }]
+        );
+    }
+
+    #[test]
+    fn next_fork() {
+        let forks = OpChainHardforks::op_mainnet();
+        let next = forks
+            .next_fork(&BlockHead::new(OP_MAINNET_BEDROCK_BLOCK, OP_MAINNET_ISTHMUS_TIMESTAMP))
+            .unwrap();
+        assert_eq!(next.fork, OpHardfork::Jovian);
+        assert_eq!(next.condition, ForkCondition::Timestamp(OP_MAINNET_JOVIAN_TIMESTAMP));
+        assert_eq!(
+            next.remaining,
+            ForkCountdown::Seconds(OP_MAINNET_JOVIAN_TIMESTAMP - OP_MAINNET_ISTHMUS_TIMESTAMP)
+        );
+
+        let next = forks.next_fork(&BlockHead::new(0, 0)).unwrap();
+        assert_eq!(next.fork, OpHardfork::Bedrock);
+        assert_eq!(next.remaining, ForkCountdown::Blocks(OP_MAINNET_BEDROCK_BLOCK));
+
+        assert_eq!(forks.next_fork(&BlockHead::new(u64::MAX, u64::MAX)), None);
+    }
+
+    #[test]
+    fn builtin_schedules_are_valid() {
+        for forks in [
+            OpHardfork::op_mainnet(),
+            OpHardfork::op_sepolia(),
+            OpHardfork::base_mainnet(),
+            OpHardfork::base_sepolia(),
+            OpHardfork::devnet(),
+        ] {
+            assert_eq!(OpChainHardforks::try_new(forks).map(|_| ()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn schedule_violations() {
+        let err = OpChainHardforks::try_new([
+            (OpHardfork::Bedrock, ForkCondition::Block(10)),
+            (OpHardfork::Regolith, ForkCondition::Timestamp(100)),
+            (OpHardfork::Regolith, ForkCondition::Timestamp(100)),
+            (OpHardfork::Canyon, ForkCondition::Block(20)),
+            (OpHardfork::Fjord, ForkCondition::Timestamp(50)),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            err.violations(),
+            [
+                ForkScheduleViolation::Duplicate { fork: OpHardfork::Regolith },
+                ForkScheduleViolation::InvalidCondition {
+                    fork: OpHardfork::Canyon,
+                    condition: ForkCondition::Block(20)
+                },
+                ForkScheduleViolation::MissingFork {
+                    fork: OpHardfork::Fjord,
+                    required: OpHardfork::Ecotone
+                },
+                ForkScheduleViolation::OutOfOrder {
+                    fork: OpHardfork::Canyon,
+                    condition: ForkCondition::Block(20),
+                    previous: OpHardfork::Regolith,
+                    previous_condition: ForkCondition::Timestamp(100),
+                },
+                ForkScheduleViolation::OutOfOrder {
+                    fork: OpHardfork::Fjord,
+                    condition: ForkCondition::Timestamp(50),
+                    previous: OpHardfork::Regolith,
+                    previous_condition: ForkCondition::Timestamp(100),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn precedes_implied_fork() {
+        struct Forks;
+
+        impl EthereumHardforks for Forks {
+            fn ethereum_fork_activation(&self, fork: EthereumHardfork) -> ForkCondition {
+                match fork {
+                    EthereumHardfork::Shanghai => ForkCondition::Timestamp(200),
+                    EthereumHardfork::Cancun => ForkCondition::Never,
+                    _ => ForkCondition::ZERO_BLOCK,
+                }
+            }
+        }
+
+        impl OpHardforks for Forks {
+            fn op_fork_activation(&self, fork: OpHardfork) -> ForkCondition {
+                match fork {
+                    OpHardfork::Bedrock => ForkCondition::ZERO_BLOCK,
+                    OpHardfork::Canyon => ForkCondition::Timestamp(100),
+                    OpHardfork::Ecotone => ForkCondition::Timestamp(300),
+                    _ => ForkCondition::Never,
+                }
+            }
+        }
+
+        assert_eq!(
+            implied_fork_violations(&Forks),
+            [
+                ForkScheduleViolation::PrecedesImpliedFork {
+                    fork: OpHardfork::Canyon,
+                    condition: ForkCondition::Timestamp(100),
+                    implied: EthereumHardfork::Shanghai,
+                    implied_condition: ForkCondition::Timestamp(200),
+                },
+                ForkScheduleViolation::PrecedesImpliedFork {
+                    fork: OpHardfork::Ecotone,
+                    condition: ForkCondition::Timestamp(300),
+                    implied: EthereumHardfork::Cancun,
+                    implied_condition: ForkCondition::Never,
+                },
+            ]
+        );
+        assert!(implied_fork_violations(&OpChainHardforks::op_mainnet()).is_empty());
+    }
+
+    #[test]
+    fn precedes_included_fork() {
+        // Ecotone includes Cancun, which builds on Shanghai as included by Canyon.
+        let err = OpChainHardforks::try_new([
+            (OpHardfork::Bedrock, ForkCondition::ZERO_BLOCK),
+            (OpHardfork::Regolith, ForkCondition::ZERO_TIMESTAMP),
+            (OpHardfork::Canyon, ForkCondition::Timestamp(300)),
+            (OpHardfork::Ecotone, ForkCondition::Timestamp(200)),
+        ])
+        .unwrap_err();
+
+        assert!(err.violations().contains(&ForkScheduleViolation::PrecedesImpliedFork {
+            fork: OpHardfork::Ecotone,
+            condition: ForkCondition::Timestamp(200),
+            implied: EthereumHardfork::Shanghai,
+            implied_condition: ForkCondition::Timestamp(300),
+        }));
+        assert!(OpChainHardforks::try_new(OpHardfork::op_mainnet()).is_ok());
+    }
+
     #[test]
     fn op_mainnet_fork_conditions() {
         use OpHardfork::*;
@@ -657,6 +1071,124 @@ mod tests {
         assert_eq!(OpHardfork::from_chain_and_timestamp(Chain::from_id(999999), 1000000), None);
     }
 
+    #[test]
+    fn op_fork_filter_keys() {
+        let op_mainnet_forks = OpChainHardforks::op_mainnet();
+        let keys = op_mainnet_forks.fork_filter_keys();
+
+        assert!(keys.contains(&ForkFilterKey::Block(OP_MAINNET_BERLIN_BLOCK)));
+        assert!(keys.contains(&ForkFilterKey::Block(OP_MAINNET_BEDROCK_BLOCK)));
+        // OP only hardforks which don't imply an Ethereum hardfork.
+        assert!(keys.contains(&ForkFilterKey::Time(OP_MAINNET_FJORD_TIMESTAMP)));
+        assert!(keys.contains(&ForkFilterKey::Time(OP_MAINNET_JOVIAN_TIMESTAMP)));
+
+        let head = Head {
+            number: OP_MAINNET_BEDROCK_BLOCK,
+            timestamp: OP_MAINNET_FJORD_TIMESTAMP,
+            ..Default::default()
+        };
+        let filter = op_mainnet_forks.fork_filter(head, Default::default(), 0);
+        assert_eq!(filter.current().next, OP_MAINNET_GRANITE_TIMESTAMP);
+    }
+
+    /// Asserts the [EIP-2124] fork ID of `forks` at every `(block, timestamp)` head.
+    ///
+    /// [EIP-2124]: https://eips.ethereum.org/EIPS/eip-2124
+    fn assert_fork_ids(
+        forks: &OpChainHardforks,
+        genesis_hash: B256,
+        genesis_timestamp: u64,
+        cases: &[((u64, u64), [u8; 4], u64)],
+    ) {
+        for &((number, timestamp), hash, next) in cases {
+            let head = Head { number, timestamp, ..Default::default() };
+            assert_eq!(
+                forks.fork_filter(head, genesis_hash, genesis_timestamp).current(),
+                ForkId { hash: ForkHash(hash), next },
+                "head {head}"
+            );
+        }
+    }
+
+    #[test]
+    fn op_mainnet_fork_ids() {
+        assert_fork_ids(
+            &OpChainHardforks::op_mainnet(),
+            b256!("7ca38a1916c42007829c55e69d3e9a73265554b586a499015373241b8a3fa48b"),
+            1_610_639_500,
+            &[
+                ((0, 0), hex!("caf517ed"), 3_950_000),
+                ((3_950_000, 0), hex!("526a2171"), 105_235_063),
+                // London, Arrow Glacier, Gray Glacier, Paris and Bedrock share the same block.
+                ((105_235_063, 0), hex!("e3398d7c"), 1_704_992_401),
+                ((105_235_063, 1_704_992_401), hex!("bdd4fdb2"), 1_710_374_401),
+                ((105_235_063, 1_710_374_401), hex!("19da4c52"), 1_720_627_201),
+                ((105_235_063, 1_720_627_201), hex!("49fbfe1e"), 1_726_070_401),
+                ((105_235_063, 1_726_070_401), hex!("44704cde"), 1_736_445_601),
+                ((105_235_063, 1_736_445_601), hex!("2bd93dc8"), 1_746_806_401),
+                ((105_235_063, 1_746_806_401), hex!("37be758f"), 1_764_086_401),
+                ((105_235_063, 1_764_086_401), hex!("33b189a9"), 0),
+            ],
+        );
+    }
+
+    #[test]
+    fn op_sepolia_fork_ids() {
+        assert_fork_ids(
+            &OpChainHardforks::op_sepolia(),
+            b256!("102de6ffb001480cc9b8b548fd05c34cd4f46ae4aa91759393db90ea0409887d"),
+            1_691_802_540,
+            &[
+                ((0, 1_691_802_540), hex!("67a40328"), 1_699_981_200),
+                ((0, 1_699_981_200), hex!("a48d6a00"), 1_708_534_800),
+                ((0, 1_708_534_800), hex!("cc17c7eb"), 1_716_998_400),
+                ((0, 1_716_998_400), hex!("540a8c5d"), 1_723_478_400),
+                ((0, 1_723_478_400), hex!("75dea41e"), 1_732_633_200),
+                ((0, 1_732_633_200), hex!("4a1c792e"), 1_744_905_600),
+                ((0, 1_744_905_600), hex!("6c625ee1"), 1_762_963_201),
+                ((0, 1_762_963_201), hex!("43d57ff4"), 0),
+            ],
+        );
+    }
+
+    #[test]
+    fn base_mainnet_fork_ids() {
+        assert_fork_ids(
+            &OpChainHardforks::base_mainnet(),
+            b256!("f712aa9241cc24369b143cf6dce85f0902a9731e70d66818a3a5845b296c73dd"),
+            1_686_789_347,
+            &[
+                ((0, 1_686_789_347), hex!("67da0260"), 1_704_992_401),
+                ((0, 1_704_992_401), hex!("3c283cb3"), 1_710_374_401),
+                ((0, 1_710_374_401), hex!("51cc98b3"), 1_720_627_201),
+                ((0, 1_720_627_201), hex!("e4010eb9"), 1_726_070_401),
+                ((0, 1_726_070_401), hex!("bc38f9ca"), 1_736_445_601),
+                ((0, 1_736_445_601), hex!("3a2af183"), 1_746_806_401),
+                ((0, 1_746_806_401), hex!("86728b4e"), 1_764_086_401),
+                ((0, 1_764_086_401), hex!("fa7170ef"), 0),
+            ],
+        );
+    }
+
+    #[test]
+    fn base_sepolia_fork_ids() {
+        assert_fork_ids(
+            &OpChainHardforks::base_sepolia(),
+            b256!("0dcc9e089e30b90ddfc55be9a37dd15bc551aeee999d2e2b51414c54eaf934e4"),
+            1_695_768_288,
+            &[
+                ((0, 1_695_768_288), hex!("b959b9f7"), 1_699_981_200),
+                ((0, 1_699_981_200), hex!("607cd5a1"), 1_708_534_800),
+                ((0, 1_708_534_800), hex!("be969b17"), 1_716_998_400),
+                ((0, 1_716_998_400), hex!("4e457a49"), 1_723_478_400),
+                ((0, 1_723_478_400), hex!("5edfa3b6"), 1_732_633_200),
+                ((0, 1_732_633_200), hex!("8b5e7629"), 1_744_905_600),
+                ((0, 1_744_905_600), hex!("060a4d1d"), 1_762_963_201),
+                ((0, 1_762_963_201), hex!("8a021a79"), 0),
+            ],
+        );
+    }
+
     // https://github.com/alloy-rs/hardforks/issues/63
     #[test]
     fn test_ethereum_fork_activation_consistency() {
@@ -668,4 +1200,90 @@ mod tests {
             let _ = op_mainnet_forks.op_fork_activation(*op_hardfork);
         }
     }
+
+    #[test]
+    fn active_at_head() {
+        let forks = OpChainHardforks::op_mainnet();
+        let head = BlockHead::new(OP_MAINNET_BEDROCK_BLOCK + 1, OP_MAINNET_ISTHMUS_TIMESTAMP);
+        let parent = BlockHead::new(OP_MAINNET_BEDROCK_BLOCK, OP_MAINNET_ISTHMUS_TIMESTAMP - 2);
+
+        assert!(forks.is_op_fork_active_at(OpHardfork::Isthmus, &head));
+        assert!(!forks.is_op_fork_active_at(OpHardfork::Isthmus, &parent));
+        assert!(forks.op_fork_transitions_at(OpHardfork::Isthmus, &head, &parent));
+        assert!(!forks.op_fork_transitions_at(OpHardfork::Holocene, &head, &parent));
+        assert!(forks.ethereum_fork_transitions_at(EthereumHardfork::Prague, &head, &parent));
+        // The TTD based merge is active from Bedrock onwards.
+        assert!(forks.is_ethereum_fork_active_at(EthereumHardfork::Paris, &parent));
+    }
+
+    #[test]
+    fn l1_origin_activation() {
+        let forks = OpChainHardforks::op_mainnet();
+        let origin = BlockHead::new(20_000_000, OP_MAINNET_HOLOCENE_TIMESTAMP);
+        let previous = BlockHead::new(19_999_999, OP_MAINNET_HOLOCENE_TIMESTAMP - 12);
+
+        // By default, the L1 origin timestamp is compared with the L2 activation timestamp.
+        assert_eq!(
+            forks.op_fork_l1_origin_activation(OpHardfork::Holocene),
+            L1OriginCondition::Timestamp(OP_MAINNET_HOLOCENE_TIMESTAMP)
+        );
+        assert!(forks.op_fork_transitions_at_l1_origin(OpHardfork::Holocene, &origin, &previous));
+        assert_eq!(
+            forks.op_fork_l1_origin_activation(OpHardfork::Bedrock),
+            L1OriginCondition::Never
+        );
+
+        let forks = forks
+            .with_l1_origin_activation(OpHardfork::Holocene, L1OriginCondition::Block(20_000_001));
+        assert!(!forks.is_op_fork_active_at_l1_origin(OpHardfork::Holocene, &origin));
+        assert!(
+            forks.is_op_fork_active_at_l1_origin(
+                OpHardfork::Holocene,
+                &BlockHead::new(20_000_001, 0)
+            )
+        );
+        // The L2 activation is unaffected.
+        assert_eq!(
+            forks.op_fork_activation(OpHardfork::Holocene),
+            ForkCondition::Timestamp(OP_MAINNET_HOLOCENE_TIMESTAMP)
+        );
+    }
+
+    #[test]
+    fn tentative_interop() {
+        let forks = OpChainHardforks::try_new(
+            OpHardfork::op_mainnet()
+                .into_iter()
+                .chain([(OpHardfork::Interop, ForkCondition::Tentative(2_000_000_000))]),
+        )
+        .unwrap();
+        assert!(!forks.is_interop_active_at_timestamp(u64::MAX));
+        assert_eq!(
+            forks.op_fork_l1_origin_activation(OpHardfork::Interop),
+            L1OriginCondition::Never
+        );
+        assert_eq!(forks.next_fork(&BlockHead::new(u64::MAX, OP_MAINNET_JOVIAN_TIMESTAMP)), None);
+    }
+
+    #[test]
+    fn op_opcodes() {
+        // OP chains get the opcodes of the Ethereum hardforks they include, e.g. `PUSH0` with
+        // Canyon and `TLOAD` with Ecotone.
+        let forks = OpChainHardforks::op_mainnet();
+        let block = OP_MAINNET_BEDROCK_BLOCK;
+        assert!(
+            !forks
+                .is_opcode_valid_at(0x5f, &BlockHead::new(block, OP_MAINNET_CANYON_TIMESTAMP - 1))
+        );
+        assert!(
+            forks.is_opcode_valid_at(0x5f, &BlockHead::new(block, OP_MAINNET_CANYON_TIMESTAMP))
+        );
+        assert!(
+            !forks
+                .is_opcode_valid_at(0x5c, &BlockHead::new(block, OP_MAINNET_ECOTONE_TIMESTAMP - 1))
+        );
+        assert!(
+            forks.is_opcode_valid_at(0x5c, &BlockHead::new(block, OP_MAINNET_ECOTONE_TIMESTAMP))
+        );
+    }
 }
@@ -0,0 +1,97 @@
+//! Precompiled contracts introduced by OP hardforks.
+
+use crate::OpHardfork;
+use alloy_hardforks::Precompile;
+
+impl OpHardfork {
+    /// Returns the precompiles the hardfork introduces on OP chains.
+    ///
+    /// The precompiles of [`Ecotone`](OpHardfork::Ecotone) and [`Isthmus`](OpHardfork::Isthmus)
+    /// are the ones of the Ethereum hardforks they include, while [`Fjord`](OpHardfork::Fjord)
+    /// introduces [RIP-7212](https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md)'s
+    /// `P256VERIFY` ahead of Ethereum.
+    pub const fn precompiles(&self) -> &'static [Precompile] {
+        match self {
+            Self::Ecotone => &[Precompile::PointEvaluation],
+            Self::Fjord => &[Precompile::P256Verify],
+            Self::Isthmus => &[
+                Precompile::Bls12G1Add,
+                Precompile::Bls12G1Msm,
+                Precompile::Bls12G2Add,
+                Precompile::Bls12G2Msm,
+                Precompile::Bls12PairingCheck,
+                Precompile::Bls12MapFpToG1,
+                Precompile::Bls12MapFp2ToG2,
+            ],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        OP_MAINNET_FJORD_TIMESTAMP, OP_MAINNET_ISTHMUS_TIMESTAMP, OpChainHardforks, OpHardforks,
+    };
+    use alloy_hardforks::{BlockHead, EthereumHardfork, EthereumHardforks, ForkCondition};
+
+    #[test]
+    fn op_precompiles() {
+        let forks = OpChainHardforks::op_mainnet();
+        let block = forks.op_fork_activation(OpHardfork::Bedrock).block_number().unwrap();
+
+        let fjord = BlockHead::new(block, OP_MAINNET_FJORD_TIMESTAMP);
+        let p256 = Precompile::P256Verify.address();
+        assert!(
+            !forks.is_op_precompile_active_at(p256, &BlockHead::new(block, fjord.timestamp - 1))
+        );
+        assert!(forks.is_op_precompile_active_at(p256, &fjord));
+        assert_eq!(
+            forks.op_precompile_activation(Precompile::P256Verify),
+            forks.op_fork_activation(OpHardfork::Fjord)
+        );
+
+        assert_eq!(forks.op_precompiles_at(&fjord).len(), 11);
+        assert_eq!(
+            forks.op_precompiles_at(&BlockHead::new(block, OP_MAINNET_ISTHMUS_TIMESTAMP)).len(),
+            Precompile::VARIANTS.len()
+        );
+        assert_eq!(
+            forks.op_precompile_activation(Precompile::EcRecover),
+            forks.ethereum_fork_activation(alloy_hardforks::EthereumHardfork::Frontier)
+        );
+    }
+
+    #[test]
+    fn op_precompiles_default_impls() {
+        // Only implements the required methods.
+        struct Forks;
+
+        impl EthereumHardforks for Forks {
+            fn ethereum_fork_activation(&self, fork: EthereumHardfork) -> ForkCondition {
+                match fork {
+                    EthereumHardfork::Osaka => ForkCondition::Never,
+                    _ => ForkCondition::ZERO_TIMESTAMP,
+                }
+            }
+        }
+
+        impl OpHardforks for Forks {
+            fn op_fork_activation(&self, fork: OpHardfork) -> ForkCondition {
+                match fork {
+                    OpHardfork::Fjord => ForkCondition::Timestamp(100),
+                    _ => ForkCondition::Never,
+                }
+            }
+        }
+
+        let p256 = Precompile::P256Verify.address();
+        assert!(Forks.is_op_precompile_active_at(p256, &BlockHead::new(0, 100)));
+        assert!(!Forks.is_op_precompile_active_at(p256, &BlockHead::new(0, 99)));
+        assert_eq!(
+            Forks.op_precompiles_at(&BlockHead::new(0, 100)).last(),
+            Some(&Precompile::P256Verify)
+        );
+    }
+}